@@ -2,11 +2,9 @@ use panda::client::Config;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut client = panda::new("your token here").await?;
+    let config = Config::new().set_large_threshold(100).build();
 
-    let config = Config::new().set_large_threshold(100);
-
-    client.set_config(config)?;
+    let mut client = panda::new_with_config("your token here", config).await?;
 
     client.start().await?;
     Ok(())
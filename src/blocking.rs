@@ -0,0 +1,204 @@
+//! Blocking version of the [`HttpClient`], enabled with the `blocking` feature.
+//!
+//! Each method blocks the current thread until the request finishes, so it must not be used
+//! inside async code, use the async [`HttpClient`] there.
+//!
+//! ```no_run
+//! # fn f() -> Result<(), Box<dyn std::error::Error>> {
+//! use panda::blocking::HttpClient;
+//!
+//! let http = HttpClient::new("Bot your token here");
+//! http.send_message("channel id", "Hello from a script!")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`HttpClient`]: ../struct.HttpClient.html
+
+use crate::{
+    error::Result,
+    models::{
+        audit_log::AuditLog,
+        channel::{Attachment, Channel, Embed, Message, OverwriteType},
+        emoji::Emoji,
+        gateway::GatewayBot,
+        guild::{Ban, Guild, GuildMember, PartialGuild, Permissions, Role},
+        invite::Invite,
+        user::User,
+        webhook::Webhook,
+    },
+    runtime,
+    utils::builders::{BanOptions, MessageBuilder, MessageQuery, WebhookExecuteBuilder},
+};
+
+use serde::Serialize;
+use std::time::{Duration, SystemTime};
+
+/// This macro creates a blocking method for each async method of the HttpClient with the same name
+macro_rules! impl_blocking_methods {
+    ($($(#[$attr: meta])* fn $name: ident($($arg: ident: $ty: ty),*) -> $ret: ty;)*) => {
+        $(
+            $(#[$attr])*
+            pub fn $name(&self, $($arg: $ty),*) -> Result<$ret> {
+                runtime::block_on(self.http.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+/// A blocking HTTP client, it has the same methods as the async [`HttpClient`], see their
+/// documentation there
+///
+/// [`HttpClient`]: ../struct.HttpClient.html
+#[derive(Clone)]
+pub struct HttpClient {
+    http: crate::HttpClient,
+}
+
+impl HttpClient {
+    /// Creates a new blocking http client, the token must have the `Bot ` prefix
+    pub fn new(token: impl Into<String>) -> HttpClient {
+        HttpClient {
+            http: crate::HttpClient::new(token),
+        }
+    }
+
+    /// Creates a new blocking http client with the given timeout for each request
+    pub fn new_with_timeout(token: impl Into<String>, timeout: Duration) -> HttpClient {
+        HttpClient {
+            http: crate::HttpClient::new_with_timeout(token, timeout),
+        }
+    }
+
+    /// Set the base URL of the requests, by default it's `https://discord.com/api/v6`
+    pub fn set_api_url(&mut self, url: impl Into<String>) {
+        self.http.set_api_url(url);
+    }
+
+    impl_blocking_methods! {
+        // Channel
+        fn get_channel(channel_id: impl AsRef<str>) -> Channel;
+        fn edit_channel(channel_id: impl AsRef<str>, body: impl Serialize) -> Channel;
+        fn delete_channel(channel_id: impl AsRef<str>) -> Channel;
+        fn get_messages(channel_id: impl AsRef<str>, limit: u8) -> Vec<Message>;
+        fn get_channel_messages(channel_id: impl AsRef<str>, query: MessageQuery) -> Vec<Message>;
+        fn get_messages_around(channel_id: impl AsRef<str>, message_id: impl AsRef<str>, limit: u8) -> Vec<Message>;
+        fn get_messages_before(channel_id: impl AsRef<str>, message_id: impl AsRef<str>, limit: u8) -> Vec<Message>;
+        fn get_messages_after(channel_id: impl AsRef<str>, message_id: impl AsRef<str>, limit: u8) -> Vec<Message>;
+        fn get_message(channel_id: impl AsRef<str>, msg_id: impl AsRef<str>) -> Message;
+        fn send_message(channel_id: impl AsRef<str>, content: impl AsRef<str>) -> Message;
+        fn send_message_with(channel_id: impl AsRef<str>, message: MessageBuilder) -> Message;
+        fn reply_to(channel_id: impl AsRef<str>, message_id: impl Into<String>, content: impl Into<String>, fail_if_not_exists: bool) -> Message;
+        fn send_files(channel_id: impl AsRef<str>, content: Option<&str>, files: Vec<(String, Vec<u8>)>) -> Message;
+        fn send_embed(channel_id: impl AsRef<str>, embed: Embed) -> Message;
+        fn add_reaction(channel_id: impl AsRef<str>, message_id: impl AsRef<str>, emoji: impl AsRef<str>) -> ();
+        fn remove_own_reaction(channel_id: impl AsRef<str>, message_id: impl AsRef<str>, emoji: impl AsRef<str>) -> ();
+        fn remove_user_reaction(
+            channel_id: impl AsRef<str>,
+            message_id: impl AsRef<str>,
+            user_id: impl AsRef<str>,
+            emoji: impl AsRef<str>
+        ) -> ();
+        fn get_reactions(
+            channel_id: impl AsRef<str>,
+            message_id: impl AsRef<str>,
+            emoji: impl AsRef<str>,
+            after: Option<&str>,
+            limit: Option<u8>
+        ) -> Vec<User>;
+        fn has_reacted(
+            channel_id: impl AsRef<str>,
+            message_id: impl AsRef<str>,
+            emoji: impl AsRef<str>,
+            user_id: impl AsRef<str>
+        ) -> bool;
+        fn remove_all_reactions(channel_id: impl AsRef<str>, message_id: impl AsRef<str>) -> ();
+        fn remove_all_emoji_reactions(
+            channel_id: impl AsRef<str>,
+            message_id: impl AsRef<str>,
+            emoji: impl AsRef<str>
+        ) -> ();
+        fn edit_message(channel_id: impl AsRef<str>, message_id: impl AsRef<str>, body: impl Serialize) -> Message;
+        fn delete_message(channel_id: impl AsRef<str>, message_id: impl AsRef<str>) -> ();
+        fn crosspost_message(channel_id: impl AsRef<str>, message_id: impl AsRef<str>) -> Message;
+        fn delete_many_messages(channel_id: impl AsRef<str>, messages: &[&str]) -> ();
+        fn delete_messages(channel_id: impl AsRef<str>, messages: &[&str]) -> ();
+        fn edit_channel_permissions(
+            channel_id: impl AsRef<str>,
+            overwrite_id: impl AsRef<str>,
+            allow: Permissions,
+            deny: Permissions,
+            kind: OverwriteType
+        ) -> ();
+        fn delete_channel_permission(channel_id: impl AsRef<str>, overwrite_id: impl AsRef<str>) -> ();
+        fn trigger_typing(channel_id: impl AsRef<str>) -> ();
+        fn get_pinned_messages(channel_id: impl AsRef<str>) -> Vec<Message>;
+        fn pin_message(channel_id: impl AsRef<str>, message_id: impl AsRef<str>) -> ();
+        fn unpin_message(channel_id: impl AsRef<str>, message_id: impl AsRef<str>) -> ();
+
+        // Guild
+        fn get_guild(guild_id: impl AsRef<str>) -> Guild;
+        fn get_guild_channels(guild_id: impl AsRef<str>) -> Vec<Channel>;
+        fn find_channel_by_name(guild_id: impl AsRef<str>, name: &str) -> Option<Channel>;
+        fn create_channel(guild_id: impl AsRef<str>, body: impl Serialize) -> Channel;
+        fn create_role(guild_id: impl AsRef<str>, body: impl Serialize) -> Role;
+        fn get_guild_roles(guild_id: impl AsRef<str>) -> Vec<Role>;
+        fn edit_role(guild_id: impl AsRef<str>, role_id: impl AsRef<str>, body: impl Serialize) -> Role;
+        fn delete_role(guild_id: impl AsRef<str>, role_id: impl AsRef<str>) -> ();
+        fn get_audit_log(
+            guild_id: impl AsRef<str>,
+            user_id: Option<&str>,
+            action_type: Option<u16>,
+            limit: Option<u8>
+        ) -> AuditLog;
+        fn get_guild_members(guild_id: impl AsRef<str>, limit: u16, after: Option<&str>) -> Vec<GuildMember>;
+        fn search_guild_members(guild_id: impl AsRef<str>, query: &str, limit: u16) -> Vec<GuildMember>;
+        fn get_guild_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> GuildMember;
+        fn modify_guild_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, body: impl Serialize) -> GuildMember;
+        fn timeout_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, until: Option<SystemTime>) -> GuildMember;
+        fn set_own_nick(guild_id: impl AsRef<str>, nick: &str) -> ();
+        fn kick_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, reason: Option<&str>) -> ();
+        fn get_guild_bans(guild_id: impl AsRef<str>) -> Vec<Ban>;
+        fn get_guild_ban(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Ban;
+        fn ban_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, options: BanOptions) -> ();
+        fn unban_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, reason: Option<&str>) -> ();
+        fn get_prune_count(guild_id: impl AsRef<str>, days: u8) -> u64;
+        fn begin_prune(guild_id: impl AsRef<str>, days: u8, compute_count: bool) -> Option<u64>;
+        fn add_member_role(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, role_id: impl AsRef<str>) -> ();
+        fn remove_member_role(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, role_id: impl AsRef<str>) -> ();
+
+        // Emoji
+        fn get_guild_emojis(guild_id: impl AsRef<str>) -> Vec<Emoji>;
+        fn create_guild_emoji(guild_id: impl AsRef<str>, name: &str, image: &[u8], roles: &[&str]) -> Emoji;
+        fn delete_guild_emoji(guild_id: impl AsRef<str>, emoji_id: impl AsRef<str>) -> ();
+
+        // User
+        fn get_current_user() -> User;
+        fn get_user(user_id: impl AsRef<str>) -> User;
+        fn get_current_user_guilds(before: Option<&str>, after: Option<&str>, limit: Option<u8>) -> Vec<PartialGuild>;
+        fn leave_guild(guild_id: impl AsRef<str>) -> ();
+        fn create_dm(user_id: impl AsRef<str>) -> Channel;
+
+        // Invite
+        fn create_channel_invite(
+            channel_id: impl AsRef<str>,
+            max_age: u64,
+            max_uses: u64,
+            temporary: bool,
+            unique: bool
+        ) -> Invite;
+        fn get_invite(code: impl AsRef<str>) -> Invite;
+        fn delete_invite(code: impl AsRef<str>) -> Invite;
+
+        // Webhook
+        fn create_webhook(channel_id: impl AsRef<str>, name: impl AsRef<str>) -> Webhook;
+        fn get_channel_webhooks(channel_id: impl AsRef<str>) -> Vec<Webhook>;
+        fn execute_webhook(webhook_id: impl AsRef<str>, token: impl AsRef<str>, message: WebhookExecuteBuilder) -> ();
+
+        // CDN
+        fn download_attachment(attachment: &Attachment) -> Vec<u8>;
+
+        // Gateway
+        fn get_gateway_bot() -> GatewayBot;
+    }
+}
@@ -0,0 +1,227 @@
+use super::{
+    config::{ConfigBuilder, Encoding},
+    Client, Config,
+};
+use crate::{
+    error::{PandaError, Result},
+    models::{gateway::Intents, user::StatusUpdate},
+    HttpClient,
+};
+
+use std::time::Duration;
+
+/// Builder to create a [`Client`] without handling the [`Config`] directly.
+///
+/// ```no_run
+/// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+/// use panda::{client::ClientBuilder, models::Intents};
+///
+/// let mut client = ClientBuilder::new()
+///     .token("your token here")
+///     .intents(Intents::GUILD_MESSAGES)
+///     .shard(0, 1)
+///     .build()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: struct.Client.html
+/// [`Config`]: struct.Config.html
+pub struct ClientBuilder<S = ()> {
+    token: Option<String>,
+    state: S,
+    config: ConfigBuilder,
+}
+
+impl ClientBuilder<()> {
+    pub fn new() -> Self {
+        ClientBuilder {
+            token: None,
+            state: (),
+            config: Config::new(),
+        }
+    }
+}
+
+impl Default for ClientBuilder<()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Sync + Send> ClientBuilder<S> {
+    /// Set the token of the bot, it's required
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+
+        self
+    }
+
+    /// Set the state that will be shared through the [`SessionData`]
+    ///
+    /// [`SessionData`]: struct.SessionData.html
+    pub fn state<T: Sync + Send>(self, state: T) -> ClientBuilder<T> {
+        ClientBuilder {
+            token: self.token,
+            state,
+            config: self.config,
+        }
+    }
+
+    /// Set the gateway intents, see [`ConfigBuilder.set_intents`]
+    ///
+    /// [`ConfigBuilder.set_intents`]: config/struct.ConfigBuilder.html#method.set_intents
+    pub fn intents(mut self, intents: Intents) -> Self {
+        self.config = self.config.set_intents(intents);
+
+        self
+    }
+
+    /// Add or remove the message content intent, see [`ConfigBuilder.set_message_content`]
+    ///
+    /// [`ConfigBuilder.set_message_content`]: config/struct.ConfigBuilder.html#method.set_message_content
+    pub fn message_content(mut self, enabled: bool) -> Self {
+        self.config = self.config.set_message_content(enabled);
+
+        self
+    }
+
+    /// Set the shard, see [`ConfigBuilder.set_shards`]
+    ///
+    /// [`ConfigBuilder.set_shards`]: config/struct.ConfigBuilder.html#method.set_shards
+    pub fn shard(mut self, shard_id: u64, num_shards: u64) -> Self {
+        self.config = self.config.set_shards(shard_id, num_shards);
+
+        self
+    }
+
+    /// Set the large threshold, see [`ConfigBuilder.set_large_threshold`]
+    ///
+    /// [`ConfigBuilder.set_large_threshold`]: config/struct.ConfigBuilder.html#method.set_large_threshold
+    pub fn large_threshold(mut self, threshold: u8) -> Self {
+        self.config = self.config.set_large_threshold(threshold);
+
+        self
+    }
+
+    /// Set the guild subscriptions, see [`ConfigBuilder.set_guild_subscriptions`]
+    ///
+    /// [`ConfigBuilder.set_guild_subscriptions`]: config/struct.ConfigBuilder.html#method.set_guild_subscriptions
+    pub fn guild_subscriptions(mut self, subscriptions: bool) -> Self {
+        self.config = self.config.set_guild_subscriptions(subscriptions);
+
+        self
+    }
+
+    /// Set the initial presence, see [`ConfigBuilder.set_presence`]
+    ///
+    /// [`ConfigBuilder.set_presence`]: config/struct.ConfigBuilder.html#method.set_presence
+    pub fn presence(mut self, presence: StatusUpdate) -> Self {
+        self.config = self.config.set_presence(presence);
+
+        self
+    }
+
+    /// Enable zlib-stream compression, see [`ConfigBuilder.set_zlib_stream`]
+    ///
+    /// [`ConfigBuilder.set_zlib_stream`]: config/struct.ConfigBuilder.html#method.set_zlib_stream
+    pub fn zlib_stream(mut self, zlib_stream: bool) -> Self {
+        self.config = self.config.set_zlib_stream(zlib_stream);
+
+        self
+    }
+
+    /// Set the gateway encoding, see [`ConfigBuilder.set_encoding`]
+    ///
+    /// [`ConfigBuilder.set_encoding`]: config/struct.ConfigBuilder.html#method.set_encoding
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.config = self.config.set_encoding(encoding);
+
+        self
+    }
+
+    /// Set the HTTP requests timeout, see [`ConfigBuilder.set_http_timeout`]
+    ///
+    /// [`ConfigBuilder.set_http_timeout`]: config/struct.ConfigBuilder.html#method.set_http_timeout
+    pub fn http_timeout(mut self, timeout: Duration) -> Self {
+        self.config = self.config.set_http_timeout(timeout);
+
+        self
+    }
+
+    /// Enable or disable the cache of guilds, channels and users, see [`ConfigBuilder.set_cache`]
+    ///
+    /// [`ConfigBuilder.set_cache`]: config/struct.ConfigBuilder.html#method.set_cache
+    pub fn cache(mut self, enabled: bool) -> Self {
+        self.config = self.config.set_cache(enabled);
+
+        self
+    }
+
+    /// Enable or disable the raw payloads handlers, see [`ConfigBuilder.set_raw_events`]
+    ///
+    /// [`ConfigBuilder.set_raw_events`]: config/struct.ConfigBuilder.html#method.set_raw_events
+    pub fn raw_events(mut self, enabled: bool) -> Self {
+        self.config = self.config.set_raw_events(enabled);
+
+        self
+    }
+
+    /// Set the size of the buffer of the gateway events, see [`ConfigBuilder.set_event_buffer`]
+    ///
+    /// [`ConfigBuilder.set_event_buffer`]: config/struct.ConfigBuilder.html#method.set_event_buffer
+    pub fn event_buffer(mut self, size: Option<usize>) -> Self {
+        self.config = self.config.set_event_buffer(size);
+
+        self
+    }
+
+    /// Set the [`HttpClient`] used by the client, see [`ConfigBuilder.set_http_client`]
+    ///
+    /// [`HttpClient`]: ../struct.HttpClient.html
+    /// [`ConfigBuilder.set_http_client`]: config/struct.ConfigBuilder.html#method.set_http_client
+    pub fn http_client(mut self, http: HttpClient) -> Self {
+        self.config = self.config.set_http_client(http);
+
+        self
+    }
+
+    /// Ignore the messages sent by bots, see [`ConfigBuilder.set_ignore_bots`]
+    ///
+    /// [`ConfigBuilder.set_ignore_bots`]: config/struct.ConfigBuilder.html#method.set_ignore_bots
+    pub fn ignore_bots(mut self, ignore: bool) -> Self {
+        self.config = self.config.set_ignore_bots(ignore);
+
+        self
+    }
+
+    /// Ignore the messages sent by the bot itself, see [`ConfigBuilder.set_ignore_self`]
+    ///
+    /// [`ConfigBuilder.set_ignore_self`]: config/struct.ConfigBuilder.html#method.set_ignore_self
+    pub fn ignore_self(mut self, ignore: bool) -> Self {
+        self.config = self.config.set_ignore_self(ignore);
+
+        self
+    }
+
+    /// Request the members of the large guilds, see [`ConfigBuilder.set_auto_request_members`]
+    ///
+    /// [`ConfigBuilder.set_auto_request_members`]: config/struct.ConfigBuilder.html#method.set_auto_request_members
+    pub fn auto_request_members(mut self, enabled: bool) -> Self {
+        self.config = self.config.set_auto_request_members(enabled);
+
+        self
+    }
+
+    /// Connect to the gateway and returns the [`Client`], it will fail if the token wasn't set.
+    ///
+    /// [`Client`]: struct.Client.html
+    pub async fn build(self) -> Result<Client<S>> {
+        let token = self
+            .token
+            .ok_or_else(|| PandaError::InvalidInput("the client token wasn't set".into()))?;
+
+        Client::new_with_state_and_config(token, self.state, self.config.build()).await
+    }
+}
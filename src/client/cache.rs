@@ -0,0 +1,318 @@
+use crate::models::{
+    channel::Channel,
+    gateway::events::DispatchEvent,
+    guild::{Guild, GuildMember, Role},
+    snowflake::Snowflake,
+    user::User,
+};
+
+use futures::lock::Mutex;
+use std::collections::HashMap;
+
+/// Cache of the guilds, channels and users received from the gateway, it's updated
+/// with the dispatched events before they are passed to the handlers
+#[derive(Default)]
+pub(crate) struct Cache {
+    data: Mutex<CacheData>,
+}
+
+#[derive(Default)]
+struct CacheData {
+    guilds: HashMap<Snowflake, Guild>,
+    channels: HashMap<Snowflake, Channel>,
+    users: HashMap<Snowflake, User>,
+    /// The members of each guild, keyed by guild id and then by user id
+    members: HashMap<Snowflake, HashMap<Snowflake, GuildMember>>,
+}
+
+impl Cache {
+    pub(crate) async fn guild(&self, guild_id: &str) -> Option<Guild> {
+        let data = self.data.lock().await;
+        let mut guild = data.guilds.get(guild_id).cloned()?;
+
+        if let Some(members) = data.members.get(guild_id) {
+            guild.members = members.values().cloned().collect();
+        }
+
+        Some(guild)
+    }
+
+    pub(crate) async fn channel(&self, channel_id: &str) -> Option<Channel> {
+        self.data.lock().await.channels.get(channel_id).cloned()
+    }
+
+    /// Inserts a channel that wasn't received from the gateway, like one requested to the API
+    pub(crate) async fn insert_channel(&self, channel: &Channel) {
+        self.data.lock().await.insert_channel(channel);
+    }
+
+    pub(crate) async fn user(&self, user_id: &str) -> Option<User> {
+        self.data.lock().await.users.get(user_id).cloned()
+    }
+
+    pub(crate) async fn member(&self, guild_id: &str, user_id: &str) -> Option<GuildMember> {
+        self.data.lock().await.members.get(guild_id)?.get(user_id).cloned()
+    }
+
+    /// Updates the cache with the given event
+    pub(crate) async fn update(&self, event: &DispatchEvent) {
+        let mut data = self.data.lock().await;
+
+        match event {
+            DispatchEvent::Ready(e) => {
+                data.users.insert(e.user.id.clone(), e.user.clone());
+            }
+            // Guild
+            DispatchEvent::GuildCreate(e) => {
+                let mut guild = e.0.clone();
+
+                // The channels of GUILD_CREATE don't have the guild id
+                for channel in &mut guild.channels {
+                    channel.guild_id = Some(guild.id.to_string());
+                    data.channels.insert(channel.id.clone(), channel.clone());
+                }
+
+                // The members are kept apart, so they can be found by their user id
+                let mut members = HashMap::with_capacity(guild.members.len());
+                for member in guild.members.drain(..) {
+                    if let Some(user) = &member.user {
+                        data.users.insert(user.id.clone(), user.clone());
+                        members.insert(user.id.clone(), member);
+                    }
+                }
+
+                data.members.insert(guild.id.clone(), members);
+                data.guilds.insert(guild.id.clone(), guild);
+            }
+            DispatchEvent::GuildUpdate(e) => {
+                let mut guild = e.0.clone();
+
+                // GUILD_UPDATE doesn't have the fields that are only sent within GUILD_CREATE
+                if let Some(old) = data.guilds.remove(&guild.id) {
+                    guild.joined_at = old.joined_at;
+                    guild.large = old.large;
+                    guild.member_count = old.member_count;
+                    guild.channels = old.channels;
+                }
+
+                data.guilds.insert(guild.id.clone(), guild);
+            }
+            DispatchEvent::GuildDelete(e) => {
+                // An unavailable guild is an outage, so it's not removed
+                if e.unavailable {
+                    if let Some(guild) = data.guilds.get_mut(&e.id) {
+                        guild.unavailable = true;
+                    }
+                } else if let Some(guild) = data.guilds.remove(&e.id) {
+                    data.members.remove(&e.id);
+
+                    for channel in guild.channels {
+                        data.channels.remove(&channel.id);
+                    }
+                }
+            }
+            DispatchEvent::GuildEmojisUpdate(e) => {
+                if let Some(guild) = data.guilds.get_mut(e.guild_id.as_str()) {
+                    guild.emojis = e.emojis.clone();
+                }
+            }
+            // Channel
+            DispatchEvent::ChannelCreate(e) => data.insert_channel(&e.0),
+            DispatchEvent::ChannelUpdate(e) => data.insert_channel(&e.0),
+            DispatchEvent::ChannelDelete(e) => {
+                data.channels.remove(&e.id);
+
+                if let Some(guild) = e.guild_id.as_ref().and_then(|id| data.guilds.get_mut(id.as_str())) {
+                    guild.channels.retain(|c| c.id != e.id);
+                }
+            }
+            // Members
+            DispatchEvent::GuildMemberAdd(e) => {
+                if let Some(guild_id) = &e.guild_id {
+                    data.insert_member(guild_id, &e.0);
+
+                    if let Some(count) = data
+                        .guilds
+                        .get_mut(guild_id.as_str())
+                        .and_then(|g| g.member_count.as_mut())
+                    {
+                        *count += 1;
+                    }
+                }
+            }
+            DispatchEvent::GuildMemberUpdate(e) => {
+                data.users.insert(e.user.id.clone(), e.user.clone());
+
+                if let Some(members) = data.members.get_mut(e.guild_id.as_str()) {
+                    if let Some(member) = members.get_mut(&e.user.id) {
+                        member.user = Some(e.user.clone());
+                        member.roles = e.roles.clone();
+                        member.nick = Some(e.nick.clone());
+                        member.communication_disabled_until = e.communication_disabled_until.clone();
+                    }
+                }
+            }
+            DispatchEvent::GuildMemberRemove(e) => {
+                if let Some(members) = data.members.get_mut(e.guild_id.as_str()) {
+                    members.remove(&e.user.id);
+                }
+
+                if let Some(guild) = data.guilds.get_mut(e.guild_id.as_str()) {
+                    if let Some(count) = guild.member_count.as_mut() {
+                        *count = count.saturating_sub(1);
+                    }
+                }
+            }
+            DispatchEvent::GuildMembersChunk(e) => {
+                for member in &e.members {
+                    data.insert_member(&e.guild_id, member);
+                }
+            }
+            // Roles
+            DispatchEvent::GuildRoleCreate(e) => data.insert_role(&e.guild_id, &e.role),
+            DispatchEvent::GuildRoleUpdate(e) => data.insert_role(&e.guild_id, &e.role),
+            DispatchEvent::GuildRoleDelete(e) => {
+                if let Some(guild) = data.guilds.get_mut(e.guild_id.as_str()) {
+                    guild.roles.retain(|r| r.id != e.role_id);
+                }
+            }
+            // User
+            DispatchEvent::UserUpdate(e) => {
+                data.users.insert(e.id.clone(), e.0.clone());
+            }
+            _ => {}
+        }
+    }
+}
+
+impl CacheData {
+    fn insert_channel(&mut self, channel: &Channel) {
+        self.channels.insert(channel.id.clone(), channel.clone());
+
+        if let Some(guild) = channel
+            .guild_id
+            .as_ref()
+            .and_then(|id| self.guilds.get_mut(id.as_str()))
+        {
+            match guild.channels.iter_mut().find(|c| c.id == channel.id) {
+                Some(c) => *c = channel.clone(),
+                None => guild.channels.push(channel.clone()),
+            }
+        }
+    }
+
+    fn insert_member(&mut self, guild_id: &str, member: &GuildMember) {
+        let user = match &member.user {
+            Some(user) => user,
+            None => return,
+        };
+
+        self.users.insert(user.id.clone(), user.clone());
+
+        if let Some(members) = self.members.get_mut(guild_id) {
+            members.insert(user.id.clone(), member.clone());
+        }
+    }
+
+    fn insert_role(&mut self, guild_id: &str, role: &Role) {
+        if let Some(guild) = self.guilds.get_mut(guild_id) {
+            match guild.roles.iter_mut().find(|r| r.id == role.id) {
+                Some(r) => *r = role.clone(),
+                None => guild.roles.push(role.clone()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::gateway::events::{ChannelDelete, GuildCreate, GuildMemberAdd, GuildMemberRemove},
+        runtime,
+    };
+    use serde_json::{json, Value};
+
+    fn user(id: &str) -> Value {
+        json!({ "id": id, "username": "user", "discriminator": "0001", "avatar": null })
+    }
+
+    fn member(id: &str) -> Value {
+        json!({ "user": user(id), "roles": [], "deaf": false, "mute": false })
+    }
+
+    fn guild_create() -> DispatchEvent {
+        let guild = json!({
+            "id": "1",
+            "name": "guild",
+            "owner_id": "10",
+            "region": "europe",
+            "afk_timeout": 300,
+            "verification_level": 0,
+            "default_message_notifications": 0,
+            "explicit_content_filter": 0,
+            "roles": [],
+            "emojis": [],
+            "mfa_level": 0,
+            "premium_tier": 0,
+            "preferred_locale": "en-US",
+            "member_count": 1,
+            "members": [member("10")],
+            "channels": [{ "id": "2", "type": 0 }, { "id": "3", "type": 0 }],
+        });
+
+        DispatchEvent::GuildCreate(GuildCreate(serde_json::from_value(guild).unwrap()))
+    }
+
+    #[test]
+    fn channel_delete_removes_guild_channel() {
+        runtime::block_on(async {
+            let cache = Cache::default();
+            cache.update(&guild_create()).await;
+
+            let channel = cache.channel("2").await.expect("channel of GUILD_CREATE not cached");
+            assert_eq!(channel.guild_id.as_deref(), Some("1"));
+
+            cache
+                .update(&DispatchEvent::ChannelDelete(ChannelDelete(channel)))
+                .await;
+
+            assert!(cache.channel("2").await.is_none());
+            assert!(cache.channel("3").await.is_some());
+
+            let guild = cache.guild("1").await.unwrap();
+            let ids: Vec<&str> = guild.channels.iter().map(|c| c.id.as_str()).collect();
+            assert_eq!(ids, ["3"]);
+        });
+    }
+
+    #[test]
+    fn member_add_then_remove() {
+        runtime::block_on(async {
+            let cache = Cache::default();
+            cache.update(&guild_create()).await;
+            assert!(cache.member("1", "10").await.is_some());
+
+            let mut added = member("20");
+            added["guild_id"] = json!("1");
+            let added = DispatchEvent::GuildMemberAdd(GuildMemberAdd(serde_json::from_value(added).unwrap()));
+            cache.update(&added).await;
+
+            assert!(cache.member("1", "20").await.is_some());
+            assert!(cache.user("20").await.is_some());
+            let guild = cache.guild("1").await.unwrap();
+            assert_eq!(guild.member_count, Some(2));
+            assert_eq!(guild.members.len(), 2);
+
+            let removed = GuildMemberRemove {
+                guild_id: "1".into(),
+                user: serde_json::from_value(user("20")).unwrap(),
+            };
+            cache.update(&DispatchEvent::GuildMemberRemove(removed)).await;
+
+            assert!(cache.member("1", "20").await.is_none());
+            assert!(cache.member("1", "10").await.is_some());
+            assert_eq!(cache.guild("1").await.unwrap().member_count, Some(1));
+        });
+    }
+}
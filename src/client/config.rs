@@ -1,9 +1,57 @@
+use crate::{
+    gateway::{GatewayOptions, DEFAULT_GATEWAY_URL},
+    http::DEFAULT_API_URL,
+    models::{
+        gateway::{commands::Command, Intents},
+        user::StatusUpdate,
+    },
+    HttpClient,
+};
+
+use std::time::Duration;
+
+/// The encoding used by the gateway messages
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Encoding {
+    /// JSON, it's the default encoding
+    Json,
+
+    /// [Erlang External Term Format](https://erlang.org/doc/apps/erts/erl_ext_dist.html), it's
+    /// more compact and faster to decode than JSON
+    Etf,
+}
+
+impl Encoding {
+    /// The value of the encoding in the gateway URL
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Etf => "etf",
+        }
+    }
+}
+
 /// Config contains all customizable options of the Client
 pub struct Config {
     pub(crate) gateway_large_treshold: u8,
     pub(crate) gateway_guilds_subscriptions: bool,
     pub(crate) gateway_shard_id: u64,
     pub(crate) gateway_num_shards: u64,
+    pub(crate) gateway_intents: Intents,
+    pub(crate) gateway_presence: Option<StatusUpdate>,
+    pub(crate) gateway_zlib_stream: bool,
+    pub(crate) gateway_encoding: Encoding,
+    pub(crate) http_timeout: Duration,
+    pub(crate) cache_enabled: bool,
+    pub(crate) raw_events: bool,
+    pub(crate) event_buffer: Option<usize>,
+    pub(crate) gateway_url: String,
+    pub(crate) api_url: String,
+    pub(crate) http_client: Option<HttpClient>,
+    pub(crate) ignore_bots: bool,
+    pub(crate) ignore_self: bool,
+    pub(crate) auto_request_members: bool,
+    pub(crate) warn_message_content: bool,
 }
 
 impl Config {
@@ -17,15 +65,72 @@ impl Config {
             gateway_guilds_subscriptions: true,
             gateway_shard_id: 0,
             gateway_num_shards: 1,
+            gateway_intents: Intents::default(),
+            gateway_presence: None,
+            gateway_zlib_stream: false,
+            gateway_encoding: Encoding::Json,
+            http_timeout: Duration::from_secs(30),
+            cache_enabled: true,
+            raw_events: false,
+            event_buffer: None,
+            gateway_url: DEFAULT_GATEWAY_URL.into(),
+            api_url: DEFAULT_API_URL.into(),
+            http_client: None,
+            ignore_bots: false,
+            ignore_self: false,
+            auto_request_members: false,
+            warn_message_content: true,
+        }
+    }
+
+    /// Returns the IDENTIFY command sent when a new session is started
+    pub(crate) fn identify(&self, token: &str) -> Command {
+        Command::new_identify(
+            token,
+            self.gateway_large_treshold,
+            self.gateway_guilds_subscriptions,
+            [self.gateway_shard_id, self.gateway_num_shards],
+            self.gateway_intents,
+            self.gateway_presence.clone(),
+            // Payload compression can't be used with zlib-stream, and ETF payloads are always binary
+            !self.gateway_zlib_stream && self.gateway_encoding == Encoding::Json,
+        )
+    }
+
+    /// Returns the options of the gateway connection
+    pub(crate) fn gateway_options(&self) -> GatewayOptions {
+        GatewayOptions {
+            url: self.gateway_url.clone(),
+            zlib_stream: self.gateway_zlib_stream,
+            encoding: self.gateway_encoding,
+            raw_events: self.raw_events,
+            event_buffer: self.event_buffer,
+            shard_id: self.gateway_shard_id,
         }
     }
 }
 
+#[derive(Clone)]
 pub struct ConfigBuilder {
     pub(crate) gateway_large_treshold: u8,
     pub(crate) gateway_guilds_subscriptions: bool,
     pub(crate) gateway_shard_id: u64,
     pub(crate) gateway_num_shards: u64,
+    pub(crate) gateway_intents: Intents,
+    pub(crate) gateway_presence: Option<StatusUpdate>,
+    pub(crate) gateway_zlib_stream: bool,
+    pub(crate) gateway_encoding: Encoding,
+    pub(crate) http_timeout: Duration,
+    pub(crate) cache_enabled: bool,
+    pub(crate) raw_events: bool,
+    pub(crate) event_buffer: Option<usize>,
+    pub(crate) gateway_url: String,
+    pub(crate) api_url: String,
+    pub(crate) http_client: Option<HttpClient>,
+    pub(crate) ignore_bots: bool,
+    pub(crate) ignore_self: bool,
+    pub(crate) auto_request_members: bool,
+    pub(crate) warn_message_content: bool,
 }
 
 impl ConfigBuilder {
@@ -35,6 +140,21 @@ impl ConfigBuilder {
             gateway_guilds_subscriptions: true,
             gateway_shard_id: 0,
             gateway_num_shards: 1,
+            gateway_intents: Intents::default(),
+            gateway_presence: None,
+            gateway_zlib_stream: false,
+            gateway_encoding: Encoding::Json,
+            http_timeout: Duration::from_secs(30),
+            cache_enabled: true,
+            raw_events: false,
+            event_buffer: None,
+            gateway_url: DEFAULT_GATEWAY_URL.into(),
+            api_url: DEFAULT_API_URL.into(),
+            http_client: None,
+            ignore_bots: false,
+            ignore_self: false,
+            auto_request_members: false,
+            warn_message_content: true,
         }
     }
 
@@ -47,6 +167,16 @@ impl ConfigBuilder {
     }
 
     /// A true value enables dispatching of guild subscription events (presence and typing events). Default true.
+    ///
+    /// The presence and typing events are most of the events received by bots in big guilds, so
+    /// disabling the subscriptions reduces a lot the number of events, but [`on_presence_update`]
+    /// and [`on_typing_start`] handlers won't be called. Discord ignores it when the intents are
+    /// set, in that case remove the [`GUILD_PRESENCES`] and [`GUILD_MESSAGE_TYPING`] intents.
+    ///
+    /// [`on_presence_update`]: ../struct.Client.html#method.on_presence_update
+    /// [`on_typing_start`]: ../struct.Client.html#method.on_typing_start
+    /// [`GUILD_PRESENCES`]: ../../models/gateway/struct.Intents.html#associatedconstant.GUILD_PRESENCES
+    /// [`GUILD_MESSAGE_TYPING`]: ../../models/gateway/struct.Intents.html#associatedconstant.GUILD_MESSAGE_TYPING
     pub fn set_guild_subscriptions(mut self, subscriptions: bool) -> Self {
         self.gateway_guilds_subscriptions = subscriptions;
 
@@ -62,6 +192,169 @@ impl ConfigBuilder {
         self
     }
 
+    /// Set the [`Intents`] sent in the IDENTIFY, only the events of the given intents will be
+    /// received. Default are all the non privileged intents. A warning is logged if they have
+    /// the messages intents but not MESSAGE_CONTENT, unless it's disabled explicitly with
+    /// [`set_message_content`].
+    ///
+    /// [`Intents`]: ../../models/gateway/struct.Intents.html
+    /// [`set_message_content`]: struct.ConfigBuilder.html#method.set_message_content
+    pub fn set_intents(mut self, intents: Intents) -> Self {
+        self.gateway_intents = intents;
+
+        self
+    }
+
+    /// Add or remove the privileged [`MESSAGE_CONTENT`] intent, without it the content of the
+    /// messages is empty, except in DMs and in the messages that mention the bot. It needs to be
+    /// enabled in the "Bot" page of the developer portal, or the connection is closed with
+    /// [`PandaError::DisallowedIntents`]. It changes the current intents, so it must be called
+    /// after [`set_intents`]. Disabling it explicitly silences the warning logged when the
+    /// messages intents are set without it. Default false.
+    ///
+    /// [`MESSAGE_CONTENT`]: ../../models/gateway/struct.Intents.html#associatedconstant.MESSAGE_CONTENT
+    /// [`PandaError::DisallowedIntents`]: ../../enum.PandaError.html#variant.DisallowedIntents
+    /// [`set_intents`]: struct.ConfigBuilder.html#method.set_intents
+    pub fn set_message_content(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.gateway_intents.insert(Intents::MESSAGE_CONTENT);
+        } else {
+            self.gateway_intents.remove(Intents::MESSAGE_CONTENT);
+        }
+        self.warn_message_content = false;
+
+        self
+    }
+
+    /// Set the initial presence of the bot, it's sent in the IDENTIFY. Default is no presence.
+    /// Use [`SessionData.update_status`] to change it later.
+    ///
+    /// [`SessionData.update_status`]: ../struct.SessionData.html#method.update_status
+    pub fn set_presence(mut self, presence: StatusUpdate) -> Self {
+        self.gateway_presence = Some(presence);
+
+        self
+    }
+
+    /// Enable zlib-stream compression of the whole gateway connection, it reduces the bandwidth
+    /// used by bots in many guilds. If it's disabled only the large payloads are compressed.
+    /// Default false.
+    pub fn set_zlib_stream(mut self, zlib_stream: bool) -> Self {
+        self.gateway_zlib_stream = zlib_stream;
+
+        self
+    }
+
+    /// Set the [`Encoding`] of the gateway messages. Default is JSON.
+    ///
+    /// [`Encoding`]: enum.Encoding.html
+    pub fn set_encoding(mut self, encoding: Encoding) -> Self {
+        self.gateway_encoding = encoding;
+
+        self
+    }
+
+    /// Set the time to wait for a response of Discord HTTP API, the requests that take longer
+    /// fail with [`PandaError::Timeout`]. Default 30 seconds.
+    ///
+    /// [`PandaError::Timeout`]: ../../enum.PandaError.html#variant.Timeout
+    pub fn set_http_timeout(mut self, timeout: Duration) -> Self {
+        self.http_timeout = timeout;
+
+        self
+    }
+
+    /// Enable the cache of guilds, channels and users, disable it to reduce the memory used.
+    /// Default true.
+    pub fn set_cache(mut self, enabled: bool) -> Self {
+        self.cache_enabled = enabled;
+
+        self
+    }
+
+    /// Send the raw payloads received from the gateway to the [`on_raw_event`] handlers, it's
+    /// useful to debug the events that aren't deserialized as expected. Each payload is
+    /// serialized again, so it's disabled by default.
+    ///
+    /// [`on_raw_event`]: ../struct.Client.html#method.on_raw_event
+    pub fn set_raw_events(mut self, enabled: bool) -> Self {
+        self.raw_events = enabled;
+
+        self
+    }
+
+    /// Set the size of the buffer of the events received from the gateway that weren't handled
+    /// yet. With a size the buffer is bounded, when it's full the gateway connection stops reading
+    /// until the client handles the events, so the memory is limited, but the heartbeats are also
+    /// delayed and a slow client can be disconnected. With `None` the buffer grows as needed.
+    /// Default `None`.
+    pub fn set_event_buffer(mut self, size: Option<usize>) -> Self {
+        self.event_buffer = size;
+
+        self
+    }
+
+    /// Set the URL of the gateway, it can be used to connect to a proxy or to a mock server
+    /// in tests. Default `wss://gateway.discord.gg`.
+    pub fn set_gateway_url(mut self, url: impl Into<String>) -> Self {
+        self.gateway_url = url.into();
+
+        self
+    }
+
+    /// Set the base URL of the API requests, it can be used to send the requests to a proxy or
+    /// to a mock server in tests. Default `https://discord.com/api/v6`.
+    pub fn set_api_url(mut self, url: impl Into<String>) -> Self {
+        self.api_url = url.into();
+
+        self
+    }
+
+    /// Set the [`HttpClient`] used by the client, instead of creating a new one. The clones of an
+    /// [`HttpClient`] share the rate limits, so the same client can be used by many shards to
+    /// avoid being rate limited. The http timeout and the API URL of the config are ignored.
+    ///
+    /// [`HttpClient`]: ../../struct.HttpClient.html
+    pub fn set_http_client(mut self, http: HttpClient) -> Self {
+        self.http_client = Some(http);
+
+        self
+    }
+
+    /// Ignore the messages sent by bots, the [`on_message_create`] handlers aren't called for
+    /// them. Default false.
+    ///
+    /// [`on_message_create`]: ../struct.Client.html#method.on_message_create
+    pub fn set_ignore_bots(mut self, ignore: bool) -> Self {
+        self.ignore_bots = ignore;
+
+        self
+    }
+
+    /// Ignore the messages sent by the bot itself, the [`on_message_create`] handlers aren't
+    /// called for them. Default false.
+    ///
+    /// [`on_message_create`]: ../struct.Client.html#method.on_message_create
+    pub fn set_ignore_self(mut self, ignore: bool) -> Self {
+        self.ignore_self = ignore;
+
+        self
+    }
+
+    /// Request all the members of the large guilds when their [`GuildCreate`] is received, the
+    /// members are received in [`GuildMembersChunk`] events. Discord only sends the online
+    /// members of the large guilds in the [`GuildCreate`], see [`set_large_threshold`]. It
+    /// requires the privileged GUILD_MEMBERS intent. Default false.
+    ///
+    /// [`GuildCreate`]: ../../models/gateway/events/struct.GuildCreate.html
+    /// [`GuildMembersChunk`]: ../../models/gateway/events/struct.GuildMembersChunk.html
+    /// [`set_large_threshold`]: struct.ConfigBuilder.html#method.set_large_threshold
+    pub fn set_auto_request_members(mut self, enabled: bool) -> Self {
+        self.auto_request_members = enabled;
+
+        self
+    }
+
     /// Build a Config struct
     pub fn build(self) -> Config {
         Config {
@@ -69,6 +362,40 @@ impl ConfigBuilder {
             gateway_guilds_subscriptions: self.gateway_guilds_subscriptions,
             gateway_shard_id: self.gateway_shard_id,
             gateway_num_shards: self.gateway_num_shards,
+            gateway_intents: self.gateway_intents,
+            gateway_presence: self.gateway_presence,
+            gateway_zlib_stream: self.gateway_zlib_stream,
+            gateway_encoding: self.gateway_encoding,
+            http_timeout: self.http_timeout,
+            cache_enabled: self.cache_enabled,
+            raw_events: self.raw_events,
+            event_buffer: self.event_buffer,
+            gateway_url: self.gateway_url,
+            api_url: self.api_url,
+            http_client: self.http_client,
+            ignore_bots: self.ignore_bots,
+            ignore_self: self.ignore_self,
+            auto_request_members: self.auto_request_members,
+            warn_message_content: self.warn_message_content,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_tungstenite::tungstenite::Message as TungsteniteMessage;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn identify_uses_config_shard() {
+        let config = Config::new().set_shards(2, 4).build();
+
+        let payload = match config.identify("Bot token").to_tungstenite_message(None) {
+            TungsteniteMessage::Text(text) => serde_json::from_str::<Value>(&text).unwrap(),
+            msg => panic!("unexpected message {:?}", msg),
+        };
+
+        assert_eq!(payload["d"]["shard"], json!([2, 4]));
+    }
+}
@@ -0,0 +1,258 @@
+use super::handler::EventHandlers;
+use crate::{error::PandaError, models::gateway::events::*, HandlerFuture, Session};
+
+use futures::future::FutureExt;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// This macro creates the EventHandler trait, with a no-op default method for each event, and the
+/// function that adds its methods to the handlers of the client
+macro_rules! event_handler_trait {
+    ($( $(#[$meta: meta])* fn $name: ident($event: ty) );* $(;)?) => {
+        /// A trait to handle all the events with one struct, an alternative to the `on_EVENT`
+        /// closures. Each method has a default implementation that does nothing, so only the
+        /// needed events must be implemented. It's added with [`Client.set_handler`].
+        ///
+        /// The methods return a [`HandlerFuture`], it's created with `async move {}.boxed()`.
+        ///
+        /// ```no_run
+        /// use futures::FutureExt;
+        /// use panda::{client::EventHandler, events::MessageCreate, HandlerFuture, Session};
+        ///
+        /// struct Bot;
+        ///
+        /// impl EventHandler for Bot {
+        ///     fn message_create(&self, s: Session<()>, msg: MessageCreate) -> HandlerFuture<'_> {
+        ///         async move {
+        ///             if msg.content == "!ping" {
+        ///                 msg.send(&s.http, "!pong").await?;
+        ///             }
+        ///             Ok(())
+        ///         }
+        ///         .boxed()
+        ///     }
+        /// }
+        /// ```
+        ///
+        /// [`Client.set_handler`]: struct.Client.html#method.set_handler
+        /// [`HandlerFuture`]: ../type.HandlerFuture.html
+        pub trait EventHandler<S = ()>: Send + Sync + 'static {
+            $(
+                $(#[$meta])*
+                fn $name(&self, _session: Session<S>, _event: $event) -> HandlerFuture<'_> {
+                    async { Ok(()) }.boxed()
+                }
+            )*
+        }
+
+        /// Adds each method of the EventHandler as a handler function, so they are called like
+        /// the `on_EVENT` closures
+        pub(crate) fn add_event_handler<S, H>(handlers: &mut EventHandlers<S>, handler: H)
+        where
+            S: Send + Sync + 'static,
+            H: EventHandler<S>,
+        {
+            let handler = Arc::new(handler);
+
+            $(
+                let h = Arc::clone(&handler);
+                handlers.$name.push(Arc::new(move |session, event| {
+                    let h = Arc::clone(&h);
+                    async move { h.$name(session, event).await }.boxed()
+                }));
+            )*
+        }
+    };
+}
+
+event_handler_trait! {
+    /// Called on [`Ready`] events
+    ///
+    /// [`Ready`]: ../models/gateway/events/struct.Ready.html
+    fn ready(Ready);
+
+    /// Called on [`ChannelCreate`] events
+    ///
+    /// [`ChannelCreate`]: ../models/gateway/events/struct.ChannelCreate.html
+    fn channel_create(ChannelCreate);
+
+    /// Called on [`ChannelUpdate`] events
+    ///
+    /// [`ChannelUpdate`]: ../models/gateway/events/struct.ChannelUpdate.html
+    fn channel_update(ChannelUpdate);
+
+    /// Called on [`ChannelDelete`] events
+    ///
+    /// [`ChannelDelete`]: ../models/gateway/events/struct.ChannelDelete.html
+    fn channel_delete(ChannelDelete);
+
+    /// Called on [`ChannelPinsUpdate`] events
+    ///
+    /// [`ChannelPinsUpdate`]: ../models/gateway/events/struct.ChannelPinsUpdate.html
+    fn channel_pins_update(ChannelPinsUpdate);
+
+    /// Called on [`GuildCreate`] events
+    ///
+    /// [`GuildCreate`]: ../models/gateway/events/struct.GuildCreate.html
+    fn guild_create(GuildCreate);
+
+    /// Called on [`GuildUpdate`] events
+    ///
+    /// [`GuildUpdate`]: ../models/gateway/events/struct.GuildUpdate.html
+    fn guild_update(GuildUpdate);
+
+    /// Called on [`GuildDelete`] events
+    ///
+    /// [`GuildDelete`]: ../models/gateway/events/struct.GuildDelete.html
+    fn guild_delete(GuildDelete);
+
+    /// Called on [`GuildBanAdd`] events
+    ///
+    /// [`GuildBanAdd`]: ../models/gateway/events/struct.GuildBanAdd.html
+    fn guild_ban_add(GuildBanAdd);
+
+    /// Called on [`GuildBanRemove`] events
+    ///
+    /// [`GuildBanRemove`]: ../models/gateway/events/struct.GuildBanRemove.html
+    fn guild_ban_remove(GuildBanRemove);
+
+    /// Called on [`GuildEmojisUpdate`] events
+    ///
+    /// [`GuildEmojisUpdate`]: ../models/gateway/events/struct.GuildEmojisUpdate.html
+    fn guild_emojis_update(GuildEmojisUpdate);
+
+    /// Called on [`GuildIntegrationsUpdate`] events
+    ///
+    /// [`GuildIntegrationsUpdate`]: ../models/gateway/events/struct.GuildIntegrationsUpdate.html
+    fn guild_integrations_update(GuildIntegrationsUpdate);
+
+    /// Called on [`GuildMemberAdd`] events
+    ///
+    /// [`GuildMemberAdd`]: ../models/gateway/events/struct.GuildMemberAdd.html
+    fn guild_member_add(GuildMemberAdd);
+
+    /// Called on [`GuildMemberRemove`] events
+    ///
+    /// [`GuildMemberRemove`]: ../models/gateway/events/struct.GuildMemberRemove.html
+    fn guild_member_remove(GuildMemberRemove);
+
+    /// Called on [`GuildMemberUpdate`] events
+    ///
+    /// [`GuildMemberUpdate`]: ../models/gateway/events/struct.GuildMemberUpdate.html
+    fn guild_member_update(GuildMemberUpdate);
+
+    /// Called on [`GuildMembersChunk`] events
+    ///
+    /// [`GuildMembersChunk`]: ../models/gateway/events/struct.GuildMembersChunk.html
+    fn guild_members_chunk(GuildMembersChunk);
+
+    /// Called on [`GuildRoleCreate`] events
+    ///
+    /// [`GuildRoleCreate`]: ../models/gateway/events/struct.GuildRoleCreate.html
+    fn guild_role_create(GuildRoleCreate);
+
+    /// Called on [`GuildRoleUpdate`] events
+    ///
+    /// [`GuildRoleUpdate`]: ../models/gateway/events/struct.GuildRoleUpdate.html
+    fn guild_role_update(GuildRoleUpdate);
+
+    /// Called on [`GuildRoleDelete`] events
+    ///
+    /// [`GuildRoleDelete`]: ../models/gateway/events/struct.GuildRoleDelete.html
+    fn guild_role_delete(GuildRoleDelete);
+
+    /// Called on [`MessageCreate`] events
+    ///
+    /// [`MessageCreate`]: ../models/gateway/events/struct.MessageCreate.html
+    fn message_create(MessageCreate);
+
+    /// Called on [`MessageUpdate`] events
+    ///
+    /// [`MessageUpdate`]: ../models/gateway/events/struct.MessageUpdate.html
+    fn message_update(MessageUpdate);
+
+    /// Called on [`MessageDelete`] events
+    ///
+    /// [`MessageDelete`]: ../models/gateway/events/struct.MessageDelete.html
+    fn message_delete(MessageDelete);
+
+    /// Called on [`MessageDeleteBulk`] events
+    ///
+    /// [`MessageDeleteBulk`]: ../models/gateway/events/struct.MessageDeleteBulk.html
+    fn message_delete_bulk(MessageDeleteBulk);
+
+    /// Called on [`MessageReactionAdd`] events
+    ///
+    /// [`MessageReactionAdd`]: ../models/gateway/events/struct.MessageReactionAdd.html
+    fn message_reaction_add(MessageReactionAdd);
+
+    /// Called on [`MessageReactionRemove`] events
+    ///
+    /// [`MessageReactionRemove`]: ../models/gateway/events/struct.MessageReactionRemove.html
+    fn message_reaction_remove(MessageReactionRemove);
+
+    /// Called on [`MessageReactionRemoveAll`] events
+    ///
+    /// [`MessageReactionRemoveAll`]: ../models/gateway/events/struct.MessageReactionRemoveAll.html
+    fn message_reaction_remove_all(MessageReactionRemoveAll);
+
+    /// Called on [`MessageReactionRemoveEmoji`] events
+    ///
+    /// [`MessageReactionRemoveEmoji`]: ../models/gateway/events/struct.MessageReactionRemoveEmoji.html
+    fn message_reaction_remove_emoji(MessageReactionRemoveEmoji);
+
+    /// Called on [`InviteCreate`] events
+    ///
+    /// [`InviteCreate`]: ../models/gateway/events/struct.InviteCreate.html
+    fn invite_create(InviteCreate);
+
+    /// Called on [`InviteDelete`] events
+    ///
+    /// [`InviteDelete`]: ../models/gateway/events/struct.InviteDelete.html
+    fn invite_delete(InviteDelete);
+
+    /// Called on [`PresenceUpdate`] events
+    ///
+    /// [`PresenceUpdate`]: ../models/gateway/events/struct.PresenceUpdate.html
+    fn presence_update(PresenceUpdate);
+
+    /// Called on [`TypingStart`] events
+    ///
+    /// [`TypingStart`]: ../models/gateway/events/struct.TypingStart.html
+    fn typing_start(TypingStart);
+
+    /// Called on [`UserUpdate`] events
+    ///
+    /// [`UserUpdate`]: ../models/gateway/events/struct.UserUpdate.html
+    fn user_update(UserUpdate);
+
+    /// Called on [`VoiceStateUpdate`] events
+    ///
+    /// [`VoiceStateUpdate`]: ../models/gateway/events/struct.VoiceStateUpdate.html
+    fn voice_state_update(VoiceStateUpdate);
+
+    /// Called on [`VoiceServerUpdate`] events
+    ///
+    /// [`VoiceServerUpdate`]: ../models/gateway/events/struct.VoiceServerUpdate.html
+    fn voice_server_update(VoiceServerUpdate);
+
+    /// Called when the client starts reconnecting to the gateway, after the error handlers
+    fn reconnecting(());
+
+    /// Called when the session is resumed after a reconnection
+    fn resumed(());
+
+    /// Called on the events that panda doesn't know yet, with the event name and its raw data
+    fn unknown_event((String, Value));
+
+    /// Called on the raw payloads received from the gateway, if they are enabled with
+    /// [`ConfigBuilder.set_raw_events`]
+    ///
+    /// [`ConfigBuilder.set_raw_events`]: config/struct.ConfigBuilder.html#method.set_raw_events
+    fn raw_event(Value);
+
+    /// Called on the gateway errors, before reconnecting or before [`Client.start`] returns
+    ///
+    /// [`Client.start`]: struct.Client.html#method.start
+    fn error(Arc<PandaError>);
+}
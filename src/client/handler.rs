@@ -1,18 +1,19 @@
 // models
 use super::session::SessionData;
-use crate::models::gateway::events::*;
+use crate::{error::PandaError, models::gateway::events::*};
 
-use std::{error::Error, sync::Arc};
+use serde_json::Value;
+use std::sync::Arc;
 
 // async
 // Futures
 use futures::future::BoxFuture;
 
-pub(crate) type EventResult = Result<(), Box<dyn Error>>;
+pub(crate) type EventResult = crate::HandlerResult;
 
 /// Helper macro to create futures function trait
 macro_rules! event_trait {
-    ($event: tt) => {
+    ($event: ty) => {
         dyn Fn(Arc<SessionData<S>>, $event) -> BoxFuture<'static, EventResult> + Send + Sync
     };
 }
@@ -52,95 +53,148 @@ type MessageReactionRemoveFn<S> = event_trait!(MessageReactionRemove);
 type MessageReactionRemoveAllFn<S> = event_trait!(MessageReactionRemoveAll);
 type MessageReactionRemoveEmojiFn<S> = event_trait!(MessageReactionRemoveEmoji);
 
+// Invite functions trait
+type InviteCreateFn<S> = event_trait!(InviteCreate);
+type InviteDeleteFn<S> = event_trait!(InviteDelete);
+
 // Presence functions trait
 type PresenceUpdateFn<S> = event_trait!(PresenceUpdate);
 type TypingStartFn<S> = event_trait!(TypingStart);
 type UserUpdateFn<S> = event_trait!(UserUpdate);
 
-type OptionBox<T> = Option<Box<T>>;
+// Voice functions trait
+type VoiceStateUpdateFn<S> = event_trait!(VoiceStateUpdate);
+type VoiceServerUpdateFn<S> = event_trait!(VoiceServerUpdate);
+
+// Connection functions trait
+type ReconnectingFn<S> = event_trait!(());
+type ResumedFn<S> = event_trait!(());
+type UnknownEventFn<S> = event_trait!((String, Value));
+type RawEventFn<S> = event_trait!(Value);
+
+// Error function trait
+type ErrorFn<S> = event_trait!(Arc<PandaError>);
 
-/// This struct it's where all functions created by the user will be saved
-pub(crate) struct EventHandler<S> {
-    pub(crate) ready: OptionBox<ReadyFn<S>>,
+type Handlers<T> = Vec<Arc<T>>;
+
+/// This struct it's where all functions created by the user will be saved, each event can have
+/// many handlers. The functions are shared, so it can be cloned to use the same handlers in many shards
+#[derive(Clone)]
+pub(crate) struct EventHandlers<S> {
+    pub(crate) ready: Handlers<ReadyFn<S>>,
 
     // Channel
-    pub(crate) channel_create: OptionBox<ChannelCreateFn<S>>,
-    pub(crate) channel_update: OptionBox<ChannelUpdateFn<S>>,
-    pub(crate) channel_delete: OptionBox<ChannelDeleteFn<S>>,
-    pub(crate) channel_pins_update: OptionBox<ChannelPinsUpdateFn<S>>,
+    pub(crate) channel_create: Handlers<ChannelCreateFn<S>>,
+    pub(crate) channel_update: Handlers<ChannelUpdateFn<S>>,
+    pub(crate) channel_delete: Handlers<ChannelDeleteFn<S>>,
+    pub(crate) channel_pins_update: Handlers<ChannelPinsUpdateFn<S>>,
 
     // Guild
-    pub(crate) guild_create: OptionBox<GuildCreateFn<S>>,
-    pub(crate) guild_update: OptionBox<GuildUpdateFn<S>>,
-    pub(crate) guild_delete: OptionBox<GuildDeleteFn<S>>,
-    pub(crate) guild_ban_add: OptionBox<GuildBanAddFn<S>>,
-    pub(crate) guild_ban_remove: OptionBox<GuildBanRemoveFn<S>>,
-    pub(crate) guild_emojis_update: OptionBox<GuildEmojisUpdateFn<S>>,
-    pub(crate) guild_integrations_update: OptionBox<GuildIntegrationsUpdateFn<S>>,
-    pub(crate) guild_member_add: OptionBox<GuildMemberAddFn<S>>,
-    pub(crate) guild_member_remove: OptionBox<GuildMemberRemoveFn<S>>,
-    pub(crate) guild_member_update: OptionBox<GuildMemberUpdateFn<S>>,
-    pub(crate) guild_members_chunk: OptionBox<GuildMembersChunkFn<S>>,
-    pub(crate) guild_role_create: OptionBox<GuildRoleCreateFn<S>>,
-    pub(crate) guild_role_update: OptionBox<GuildRoleUpdateFn<S>>,
-    pub(crate) guild_role_delete: OptionBox<GuildRoleDeleteFn<S>>,
+    pub(crate) guild_create: Handlers<GuildCreateFn<S>>,
+    pub(crate) guild_update: Handlers<GuildUpdateFn<S>>,
+    pub(crate) guild_delete: Handlers<GuildDeleteFn<S>>,
+    pub(crate) guild_ban_add: Handlers<GuildBanAddFn<S>>,
+    pub(crate) guild_ban_remove: Handlers<GuildBanRemoveFn<S>>,
+    pub(crate) guild_emojis_update: Handlers<GuildEmojisUpdateFn<S>>,
+    pub(crate) guild_integrations_update: Handlers<GuildIntegrationsUpdateFn<S>>,
+    pub(crate) guild_member_add: Handlers<GuildMemberAddFn<S>>,
+    pub(crate) guild_member_remove: Handlers<GuildMemberRemoveFn<S>>,
+    pub(crate) guild_member_update: Handlers<GuildMemberUpdateFn<S>>,
+    pub(crate) guild_members_chunk: Handlers<GuildMembersChunkFn<S>>,
+    pub(crate) guild_role_create: Handlers<GuildRoleCreateFn<S>>,
+    pub(crate) guild_role_update: Handlers<GuildRoleUpdateFn<S>>,
+    pub(crate) guild_role_delete: Handlers<GuildRoleDeleteFn<S>>,
 
     // Message
-    pub(crate) message_create: OptionBox<MessageCreateFn<S>>,
-    pub(crate) message_update: OptionBox<MessageUpdateFn<S>>,
-    pub(crate) message_delete: OptionBox<MessageDeleteFn<S>>,
-    pub(crate) message_delete_bulk: OptionBox<MessageDeleteBulkFn<S>>,
-    pub(crate) message_reaction_add: OptionBox<MessageReactionAddFn<S>>,
-    pub(crate) message_reaction_remove: OptionBox<MessageReactionRemoveFn<S>>,
-    pub(crate) message_reaction_remove_all: OptionBox<MessageReactionRemoveAllFn<S>>,
-    pub(crate) message_reaction_remove_emoji: OptionBox<MessageReactionRemoveEmojiFn<S>>,
+    pub(crate) message_create: Handlers<MessageCreateFn<S>>,
+    pub(crate) message_update: Handlers<MessageUpdateFn<S>>,
+    pub(crate) message_delete: Handlers<MessageDeleteFn<S>>,
+    pub(crate) message_delete_bulk: Handlers<MessageDeleteBulkFn<S>>,
+    pub(crate) message_reaction_add: Handlers<MessageReactionAddFn<S>>,
+    pub(crate) message_reaction_remove: Handlers<MessageReactionRemoveFn<S>>,
+    pub(crate) message_reaction_remove_all: Handlers<MessageReactionRemoveAllFn<S>>,
+    pub(crate) message_reaction_remove_emoji: Handlers<MessageReactionRemoveEmojiFn<S>>,
+
+    // Invite
+    pub(crate) invite_create: Handlers<InviteCreateFn<S>>,
+    pub(crate) invite_delete: Handlers<InviteDeleteFn<S>>,
 
     // Presence
-    pub(crate) presence_update: OptionBox<PresenceUpdateFn<S>>,
-    pub(crate) typing_start: OptionBox<TypingStartFn<S>>,
-    pub(crate) user_update: OptionBox<UserUpdateFn<S>>,
+    pub(crate) presence_update: Handlers<PresenceUpdateFn<S>>,
+    pub(crate) typing_start: Handlers<TypingStartFn<S>>,
+    pub(crate) user_update: Handlers<UserUpdateFn<S>>,
+
+    // Voice
+    pub(crate) voice_state_update: Handlers<VoiceStateUpdateFn<S>>,
+    pub(crate) voice_server_update: Handlers<VoiceServerUpdateFn<S>>,
+
+    // Connection
+    pub(crate) reconnecting: Handlers<ReconnectingFn<S>>,
+    pub(crate) resumed: Handlers<ResumedFn<S>>,
+    pub(crate) unknown_event: Handlers<UnknownEventFn<S>>,
+    pub(crate) raw_event: Handlers<RawEventFn<S>>,
+
+    // Error
+    pub(crate) error: Handlers<ErrorFn<S>>,
 }
 
-impl<S> EventHandler<S> {
+impl<S> EventHandlers<S> {
     pub(crate) fn new() -> Self {
         Self {
-            ready: None,
+            ready: Vec::new(),
 
             // Channel
-            channel_create: None,
-            channel_update: None,
-            channel_delete: None,
-            channel_pins_update: None,
+            channel_create: Vec::new(),
+            channel_update: Vec::new(),
+            channel_delete: Vec::new(),
+            channel_pins_update: Vec::new(),
 
             // Guild
-            guild_create: None,
-            guild_update: None,
-            guild_delete: None,
-            guild_ban_add: None,
-            guild_ban_remove: None,
-            guild_emojis_update: None,
-            guild_integrations_update: None,
-            guild_member_add: None,
-            guild_member_remove: None,
-            guild_member_update: None,
-            guild_members_chunk: None,
-            guild_role_create: None,
-            guild_role_update: None,
-            guild_role_delete: None,
+            guild_create: Vec::new(),
+            guild_update: Vec::new(),
+            guild_delete: Vec::new(),
+            guild_ban_add: Vec::new(),
+            guild_ban_remove: Vec::new(),
+            guild_emojis_update: Vec::new(),
+            guild_integrations_update: Vec::new(),
+            guild_member_add: Vec::new(),
+            guild_member_remove: Vec::new(),
+            guild_member_update: Vec::new(),
+            guild_members_chunk: Vec::new(),
+            guild_role_create: Vec::new(),
+            guild_role_update: Vec::new(),
+            guild_role_delete: Vec::new(),
 
             // Message
-            message_create: None,
-            message_update: None,
-            message_delete: None,
-            message_delete_bulk: None,
-            message_reaction_add: None,
-            message_reaction_remove: None,
-            message_reaction_remove_all: None,
-            message_reaction_remove_emoji: None,
+            message_create: Vec::new(),
+            message_update: Vec::new(),
+            message_delete: Vec::new(),
+            message_delete_bulk: Vec::new(),
+            message_reaction_add: Vec::new(),
+            message_reaction_remove: Vec::new(),
+            message_reaction_remove_all: Vec::new(),
+            message_reaction_remove_emoji: Vec::new(),
+            // Invite
+            invite_create: Vec::new(),
+            invite_delete: Vec::new(),
+
             // Presence
-            presence_update: None,
-            typing_start: None,
-            user_update: None,
+            presence_update: Vec::new(),
+            typing_start: Vec::new(),
+            user_update: Vec::new(),
+
+            // Voice
+            voice_state_update: Vec::new(),
+            voice_server_update: Vec::new(),
+
+            // Connection
+            reconnecting: Vec::new(),
+            resumed: Vec::new(),
+            unknown_event: Vec::new(),
+            raw_event: Vec::new(),
+
+            // Error
+            error: Vec::new(),
         }
     }
 }
@@ -1,39 +1,67 @@
 //! # Panda Client
 
 //modules
+mod builder;
+mod cache;
 pub mod config;
+mod event_handler;
 mod handler;
 mod session;
 
-pub use config::Config;
+pub use builder::ClientBuilder;
+pub use config::{Config, Encoding};
+pub use event_handler::EventHandler;
 pub use session::SessionData;
-use handler::EventHandler;
+use cache::Cache;
+use handler::EventHandlers;
 
 use crate::{
     error::{PandaError, Result},
     runtime,
     gateway::{heartbeat, GatewayConnection},
-    models::gateway::{
-        commands::Command,
-        events::*,
-        events::{DispatchEvent, Event},
+    HttpClient,
+    models::{
+        channel::Message,
+        gateway::{
+            commands::Command,
+            events::*,
+            events::{DispatchEvent, Event},
+            Intents,
+        },
+        snowflake::Snowflake,
     },
 };
 
-use futures::{sink::SinkExt, stream::StreamExt, FutureExt};
-use std::{future::Future, sync::Arc};
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    future::{self, Either},
+    sink::SinkExt,
+    stream::{Stream, StreamExt},
+    FutureExt,
+};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// Set when the missing MESSAGE_CONTENT intent warning is logged, so it's logged once per process
+static MESSAGE_CONTENT_WARNED: AtomicBool = AtomicBool::new(false);
 
-/// This macro it's used to handle all dispatched events of handler::EventHandler
+/// This macro it's used to handle all dispatched events of handler::EventHandlers
 macro_rules! handle_event {
     ($client: ident, $kind: ident, $event: expr) => {
-        if let Some(func) = &($client).handler.$kind {
+        for func in &($client).handler.$kind {
+            let shard_id = $client.config.gateway_shard_id;
             let session = $client.session.clone();
-            let future = func(session, $event);
-            // CRATE ASDJASLDJAS
+            let future = func(session, $event.clone());
+
+            // The handlers can use `?`, their errors are logged with the event name
             crate::runtime::spawn(async move {
                 if let Err(e) = future.await {
-                    // TODO: Add display and event name
-                    log::error!("Handler error: {:?}", e);
+                    log::error!("[shard {}] Error in {} handler: {}", shard_id, stringify!($kind), e);
                 };
             });
         }
@@ -49,58 +77,326 @@ macro_rules! impl_on_event_fn {
             pub fn $fn_name<F, Fut>(&mut self, func: F)
             where
                 F: Fn(Arc<SessionData<S>>, $event) -> Fut + Sync + Send + 'static,
-                Fut: Future<Output=handler::EventResult> + Send + 'static
+                Fut: Future<Output=crate::client::handler::EventResult> + Send + 'static
             {
-                self.handler.$event_name = Some(Box::new(move |m, r| func(m, r).boxed() ))
+                self.handler.$event_name.push(Arc::new(move |m, r| func(m, r).boxed() ))
             }
         )*
     };
 }
 
+/// This macro has all the "on_EVENT" methods, it's used by Client and ShardManager
+macro_rules! impl_event_handler_methods {
+    () => {
+        impl_on_event_fn! {
+            /// Add a handler function for [`Ready`] event
+            ///
+            /// [`Ready`]: ../models/gateway/events/struct.Ready.html
+            pub fn on_ready(ready, Ready);
+
+
+            // *******************************************************************************
+            // * CHANNEL METHODS
+            // *******************************************************************************
+
+
+            /// Add a handler function for [`ChannelCreate`] event
+            ///
+            /// [`ChannelCreate`]: ../models/gateway/events/struct.ChannelCreate.html
+            pub fn on_channel_create(channel_create, ChannelCreate);
+
+            /// Add a handler function for [`ChannelUpdate`] event
+            ///
+            /// [`ChannelUpdate`]: ../models/gateway/events/struct.ChannelUpdate.html
+            pub fn on_channel_update(channel_update, ChannelUpdate);
+
+            /// Add a handler function for [`ChannelDelete`] event
+            ///
+            /// [`ChannelDelete`]: ../models/gateway/events/struct.ChannelDelete.html
+            pub fn on_channel_delete(channel_delete, ChannelDelete);
+
+            /// Add a handler function for [`ChannelPinsUpdate`] event
+            ///
+            /// [`ChannelPinsUpdate`]: ../models/gateway/events/struct.ChannelPinsUpdate.html
+            pub fn on_channel_pins_update(channel_pins_update, ChannelPinsUpdate);
+
+
+            // *******************************************************************************
+            // * GUILD METHODS
+            // *******************************************************************************
+
+
+            /// Add a handler function for [`GuildCreate`] event
+            ///
+            /// [`GuildCreate`]: ../models/gateway/events/struct.GuildCreate.html
+            pub fn on_guild_create(guild_create, GuildCreate);
+
+            /// Add a handler function for [`GuildUpdate`] event
+            ///
+            /// [`GuildUpdate`]: ../models/gateway/events/struct.GuildUpdate.html
+            pub fn on_guild_update(guild_update, GuildUpdate);
+
+            /// Add a handler function for [`GuildDelete`] event
+            ///
+            /// [`GuildDelete`]: ../models/gateway/events/struct.GuildDelete.html
+            pub fn on_guild_delete(guild_delete, GuildDelete);
+
+            /// Add a handler function for [`GuildBanAdd`] event
+            ///
+            /// [`GuildBanAdd`]: ../models/gateway/events/struct.GuildBanAdd.html
+            pub fn on_guild_ban_add(guild_ban_add, GuildBanAdd);
+
+            /// Add a handler function for [`GuildBanRemove`] event
+            ///
+            /// [`GuildBanRemove`]: ../models/gateway/events/struct.GuildBanRemove.html
+            pub fn on_guild_ban_remove(guild_ban_remove, GuildBanRemove);
+
+            /// Add a handler function for [`GuildEmojisUpdate`] event
+            ///
+            /// [`GuildEmojisUpdate`]: ../models/gateway/events/struct.GuildEmojisUpdate.html
+            pub fn on_guild_emojis_update(guild_emojis_update, GuildEmojisUpdate);
+
+            /// Add a handler function for [`GuildIntegrationsUpdate`] event
+            ///
+            /// [`GuildIntegrationsUpdate`]: ../models/gateway/events/struct.GuildIntegrationsUpdate.html
+            pub fn on_guild_integrations_update(guild_integrations_update, GuildIntegrationsUpdate);
+
+            /// Add a handler function for [`GuildMemberAdd`] event
+            ///
+            /// [`GuildMemberAdd`]: ../models/gateway/events/struct.GuildMemberAdd.html
+            pub fn on_guild_member_add(guild_member_add, GuildMemberAdd);
+
+            /// Add a handler function for [`GuildMemberUpdate`] event
+            ///
+            /// [`GuildMemberUpdate`]: ../models/gateway/events/struct.GuildMemberUpdate.html
+            pub fn on_guild_member_update(guild_member_update, GuildMemberUpdate);
+
+            /// Add a handler function for [`GuildMemberRemove`] event
+            ///
+            /// [`GuildMemberRemove`]: ../models/gateway/events/struct.GuildMemberRemove.html
+            pub fn on_guild_member_remove(guild_member_remove, GuildMemberRemove);
+
+            /// Add a handler function for [`GuildMembersChunk`] event
+            ///
+            /// [`GuildMembersChunk`]: ../models/gateway/events/struct.GuildMembersChunk.html
+            pub fn on_guild_members_chunk(guild_members_chunk, GuildMembersChunk);
+
+            /// Add a handler function for [`GuildRoleCreate`] event
+            ///
+            /// [`GuildRoleCreate`]: ../models/gateway/events/struct.GuildRoleCreate.html
+            pub fn on_guild_role_create(guild_role_create, GuildRoleCreate);
+
+            /// Add a handler function for [`GuildRoleUpdate`] event
+            ///
+            /// [`GuildRoleUpdate`]: ../models/gateway/events/struct.GuildRoleUpdate.html
+            pub fn on_guild_role_update(guild_role_update, GuildRoleUpdate);
+
+            /// Add a handler function for [`GuildRoleDelete`] event
+            ///
+            /// [`GuildRoleDelete`]: ../models/gateway/events/struct.GuildRoleDelete.html
+            pub fn on_guild_role_delete(guild_role_delete, GuildRoleDelete);
+
+
+            // *******************************************************************************
+            // * INVITE METHODS
+            // *******************************************************************************
+
+
+            /// Add a handler function for [`InviteCreate`] event
+            ///
+            /// [`InviteCreate`]: ../models/gateway/events/struct.InviteCreate.html
+            pub fn on_invite_create(invite_create, InviteCreate);
+
+            /// Add a handler function for [`InviteDelete`] event
+            ///
+            /// [`InviteDelete`]: ../models/gateway/events/struct.InviteDelete.html
+            pub fn on_invite_delete(invite_delete, InviteDelete);
+
+
+            // *******************************************************************************
+            // * MESSAGE METHODS
+            // *******************************************************************************
+
+
+            /// Add a handler function for [`MessageCreate`] event
+            ///
+            /// [`MessageCreate`]: ../models/gateway/events/struct.MessageCreate.html
+            pub fn on_message_create(message_create, MessageCreate);
+
+            /// Add a handler function for [`MessageUpdate`] event
+            ///
+            /// [`MessageUpdate`]: ../models/gateway/events/struct.MessageUpdate.html
+            pub fn on_message_update(message_update, MessageUpdate);
+
+            /// Add a handler function for [`MessageDelete`] event
+            ///
+            /// [`MessageDelete`]: ../models/gateway/events/struct.MessageDelete.html
+            pub fn on_message_delete(message_delete, MessageDelete);
+
+            /// Add a handler function for [`MessageDeleteBulk`] event
+            ///
+            /// [`MessageDeleteBulk`]: ../models/gateway/events/struct.MessageDeleteBulk.html
+            pub fn on_message_delete_bulk(message_delete_bulk, MessageDeleteBulk);
+
+            /// Add a handler function for [`MessageReactionAdd`] event
+            ///
+            /// [`MessageReactionAdd`]: ../models/gateway/events/struct.MessageReactionAdd.html
+            pub fn on_message_reaction_add(message_reaction_add, MessageReactionAdd);
+
+            /// Add a handler function for [`MessageReactionRemove`] event
+            ///
+            /// [`MessageReactionRemove`]: ../models/gateway/events/struct.MessageReactionRemove.html
+            pub fn on_message_reaction_remove(message_reaction_remove, MessageReactionRemove);
+
+            /// Add a handler function for [`MessageReactionRemoveAll`] event
+            ///
+            /// [`MessageReactionRemoveAll`]: ../models/gateway/events/struct.MessageReactionRemoveAll.html
+            pub fn on_message_reaction_remove_all(message_reaction_remove_all, MessageReactionRemoveAll);
+
+            /// Add a handler function for [`MessageReactionRemoveEmoji`] event
+            ///
+            /// [`MessageReactionRemoveEmoji`]: ../models/gateway/events/struct.MessageReactionRemoveEmoji.html
+            pub fn on_message_reaction_remove_emoji(message_reaction_remove_emoji, MessageReactionRemoveEmoji);
+
+
+            // *******************************************************************************
+            // * PRESENCE METHODS
+            // *******************************************************************************
+
+
+            /// Add a handler function for [`PresenceUpdate`] event
+            ///
+            /// [`PresenceUpdate`]: ../models/gateway/events/struct.PresenceUpdate.html
+            pub fn on_presence_update(presence_update, PresenceUpdate);
+
+            /// Add a handler function for [`TypingStart`] event
+            ///
+            /// [`TypingStart`]: ../models/gateway/events/struct.TypingStart.html
+            pub fn on_typing_start(typing_start, TypingStart);
+
+            /// Add a handler function for [`UserUpdate`] event
+            ///
+            /// [`UserUpdate`]: ../models/gateway/events/struct.UserUpdate.html
+            pub fn on_user_update(user_update, UserUpdate);
+
+
+            // *******************************************************************************
+            // * VOICE METHODS
+            // *******************************************************************************
+
+
+            /// Add a handler function for [`VoiceStateUpdate`] event
+            ///
+            /// [`VoiceStateUpdate`]: ../models/gateway/events/struct.VoiceStateUpdate.html
+            pub fn on_voice_state_update(voice_state_update, VoiceStateUpdate);
+
+            /// Add a handler function for [`VoiceServerUpdate`] event
+            ///
+            /// [`VoiceServerUpdate`]: ../models/gateway/events/struct.VoiceServerUpdate.html
+            pub fn on_voice_server_update(voice_server_update, VoiceServerUpdate);
+
+
+            // *******************************************************************************
+            // * CONNECTION METHODS
+            // *******************************************************************************
+
+
+            /// Add a handler function called when the client starts reconnecting to the gateway,
+            /// after the error handlers
+            pub fn on_reconnecting(reconnecting, ());
+
+            /// Add a handler function called when the session is resumed after a reconnection,
+            /// the events that were missed are received before it
+            pub fn on_resumed(resumed, ());
+
+            /// Add a handler function for the events that panda doesn't know yet, it receives
+            /// the event name and its raw data
+            pub fn on_unknown_event(unknown_event, (String, serde_json::Value));
+
+            /// Add a handler function for the raw payloads received from the gateway, they are
+            /// only received if it's enabled with [`ConfigBuilder.set_raw_events`]
+            ///
+            /// [`ConfigBuilder.set_raw_events`]: config/struct.ConfigBuilder.html#method.set_raw_events
+            pub fn on_raw_event(raw_event, serde_json::Value);
+
+
+            // *******************************************************************************
+            // * ERROR METHODS
+            // *******************************************************************************
+
+
+            /// Add a handler function for the gateway errors, it's called before reconnecting or
+            /// before [`start`] returns an unrecoverable error like [`AuthenticationFailed`]
+            ///
+            /// [`start`]: struct.Client.html#method.start
+            /// [`AuthenticationFailed`]: ../enum.PandaError.html#variant.AuthenticationFailed
+            pub fn on_error(error, Arc<PandaError>)
+        }
+
+        /// Add all the methods of an [`EventHandler`] as handlers, the functions added with
+        /// the `on_EVENT` methods are still called
+        ///
+        /// [`EventHandler`]: trait.EventHandler.html
+        pub fn set_handler(&mut self, handler: impl EventHandler<S>)
+        where
+            S: 'static,
+        {
+            event_handler::add_event_handler(&mut self.handler, handler);
+        }
+    };
+}
+
+// It's declared after the macros because it uses them
+mod shard_manager;
+pub use shard_manager::ShardManager;
+
 /// Client it's the main struct of Panda library, it receives and handle all discord events
 pub struct Client<S> {
-    handler: EventHandler<S>,
+    handler: EventHandlers<S>,
     config: Config,
     token: String,
     // SessionData will be shared between tasks, and it will be passed to the handler events
     session: Arc<SessionData<S>>,
     gateway: GatewayConnection,
+    // Receives the shutdown signal sent by SessionData
+    shutdown_rx: UnboundedReceiver<()>,
+    // Reconnections since the last successful IDENTIFY/RESUME, used for the backoff
+    reconnect_attempts: u32,
+    // Senders of the streams returned by Client.events
+    event_senders: Vec<UnboundedSender<DispatchEvent>>,
+    // ID of the bot user, received in the READY event
+    user_id: Option<Snowflake>,
 }
 
 impl<S: Sync + Send> Client<S> {
     /// Create a new Panda Client with the default configs
     pub async fn new(token: impl Into<String>) -> Result<Client<()>> {
-        // Create a new gateway connection
-        let gateway = GatewayConnection::new().await?;
-
-        // Get a channel (to_gateway) to able session send commands
-        // like RequestGuildMembers, UpdateStatus, etc..
-        let to_gateway_ch = gateway.to_gateway.clone();
-
-        // Add Bot prefix to the token if it doesn't have
-        let mut token = token.into();
-        if !token.starts_with("Bot ") {
-            token.insert_str(0, "Bot ");
-        }
-
-        let mut this = Client {
-            handler: EventHandler::new(),
-            config: Config::new_default(),
-            token: token.clone(),
-            session: Arc::new(SessionData::new(token, (), to_gateway_ch)),
-            gateway,
-        };
-
-        // Send identify and spawn heartbeater
-        this.clean_connect().await;
+        Client::<()>::connect(token, (), Config::new_default()).await
+    }
 
-        Ok(this)
+    /// Create a new Panda Client with personalized configs, it should be used instead of
+    /// [`set_config`] because the config is needed when the client connects to the gateway.
+    ///
+    /// [`set_config`]: struct.Client.html#method.set_config
+    pub async fn new_with_config(token: impl Into<String>, config: Config) -> Result<Client<()>> {
+        Client::<()>::connect(token, (), config).await
     }
 
     /// Create a new Panda Client with state
     pub async fn new_with_state(token: impl Into<String>, state: S) -> Result<Self> {
-        // Create a new gateway connection
-        let gateway = GatewayConnection::new().await?;
+        Self::connect(token, state, Config::new_default()).await
+    }
+
+    /// Create a new Panda Client with state and personalized configs
+    pub async fn new_with_state_and_config(token: impl Into<String>, state: S, config: Config) -> Result<Self> {
+        Self::connect(token, state, config).await
+    }
+
+    /// Connects to the gateway and sends the IDENTIFY with the given config
+    async fn connect(token: impl Into<String>, state: S, config: Config) -> Result<Self> {
+        // Create a new gateway connection, the last sequence is shared with the session
+        let last_sequence = Arc::new(AtomicU64::default());
+        let gateway = GatewayConnection::new(Arc::clone(&last_sequence), config.gateway_options()).await?;
 
         // Get a channel (to_gateway) to able session send commands
         // like RequestGuildMembers, UpdateStatus, etc..
@@ -112,38 +408,143 @@ impl<S: Sync + Send> Client<S> {
             token.insert_str(0, "Bot ");
         }
 
+        let (shutdown_tx, shutdown_rx) = mpsc::unbounded();
+        let http = match &config.http_client {
+            Some(http) => http.clone(),
+            None => {
+                let mut http = HttpClient::new_with_timeout(token.clone(), config.http_timeout);
+                http.set_api_url(config.api_url.as_str());
+                http
+            }
+        };
+        let cache = if config.cache_enabled {
+            Some(Cache::default())
+        } else {
+            None
+        };
+
         let mut this = Self {
-            handler: EventHandler::new(),
-            config: Config::new_default(),
-            token: token.clone(),
-            session: Arc::new(SessionData::new(token, state, to_gateway_ch)),
+            handler: EventHandlers::new(),
+            config,
+            token,
+            session: Arc::new(SessionData::new(
+                http,
+                state,
+                to_gateway_ch,
+                last_sequence,
+                shutdown_tx,
+                cache,
+            )),
             gateway,
+            shutdown_rx,
+            reconnect_attempts: 0,
+            event_senders: Vec::new(),
+            user_id: None,
         };
 
+        // The messages are received without content, it's easy to miss in the message handlers.
+        // It's only logged once, even if there are many clients (like the shards of a ShardManager)
+        let intents = this.config.gateway_intents;
+        if this.config.warn_message_content
+            && (intents.contains(Intents::GUILD_MESSAGES) || intents.contains(Intents::DIRECT_MESSAGES))
+            && !intents.contains(Intents::MESSAGE_CONTENT)
+            && !MESSAGE_CONTENT_WARNED.swap(true, Ordering::Relaxed)
+        {
+            log::warn!(
+                "[shard {}] The MESSAGE_CONTENT intent isn't set, the content of the messages will be empty",
+                this.config.gateway_shard_id
+            );
+        }
+
         // Send identify and spawn heartbeater
         this.clean_connect().await;
 
         Ok(this)
     }
 
-    /// Create a new "discord" Client with personalized configs
+    /// Set personalized configs, the gateway options (like shards or intents) will be used
+    /// only in the next IDENTIFY, use [`new_with_config`] to use them from the first connection.
+    ///
+    /// [`new_with_config`]: struct.Client.html#method.new_with_config
     pub fn set_config(&mut self, config: config::ConfigBuilder) -> Result<()> {
         self.config = config.build();
 
         Ok(())
     }
 
-    /// Start the bot connection process
+    /// Returns the [`SessionData`] shared with the handlers, it can be used to call
+    /// [`SessionData.shutdown`] from outside of the handlers.
+    ///
+    /// [`SessionData`]: struct.SessionData.html
+    /// [`SessionData.shutdown`]: struct.SessionData.html#method.shutdown
+    pub fn session(&self) -> Arc<SessionData<S>> {
+        Arc::clone(&self.session)
+    }
+
+    /// Returns a Stream with all the [`DispatchEvent`]s received after [`start`] is called, it's
+    /// an alternative to the `on_*` handlers, which still receive the events. The stream ends
+    /// when the client is dropped, so `start` must run in another task.
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::StreamExt;
+    /// use panda::events::DispatchEvent;
+    ///
+    /// let mut client = panda::new("your token here").await?;
+    /// let mut events = client.events();
+    ///
+    /// tokio::spawn(async move { client.start().await });
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     if let DispatchEvent::MessageCreate(msg) = event {
+    ///         println!("{}", msg.content);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`DispatchEvent`]: ../events/enum.DispatchEvent.html
+    /// [`start`]: struct.Client.html#method.start
+    pub fn events(&mut self) -> impl Stream<Item = DispatchEvent> {
+        let (tx, rx) = mpsc::unbounded();
+        self.event_senders.push(tx);
+
+        rx
+    }
+
+    /// Start the bot connection process, it returns `Ok(())` when [`SessionData.shutdown`] is called
+    ///
+    /// [`SessionData.shutdown`]: struct.SessionData.html#method.shutdown
     pub async fn start(&mut self) -> Result<()> {
         // Connection loop
         loop {
-            if let Some(event) = self.gateway.from_gateway.next().await {
+            // Wait for the next event, or stop if a shutdown was requested
+            let event = match future::select(self.gateway.from_gateway.next(), self.shutdown_rx.next()).await {
+                Either::Left((event, _)) => event,
+                Either::Right(_) => break,
+            };
+
+            if let Some(event) = event {
+                // Update the cache before the handlers receive the event
+                if let (Event::Dispatch(d), Some(cache)) = (&event, &self.session.cache) {
+                    cache.update(d).await;
+                }
+
+                // Send the event to the streams, the dropped ones are removed
+                if let Event::Dispatch(d) = &event {
+                    self.event_senders.retain(|tx| tx.unbounded_send(d.clone()).is_ok());
+                }
+
                 match event {
                     Event::Dispatch(d) => match d {
                         DispatchEvent::Ready(e) => {
                             // Save SessionData id
                             let id = e.session_id.clone();
+                            log::info!("[shard {}] Session {} started", self.config.gateway_shard_id, id);
                             self.session.set_id(id).await;
+                            self.reconnect_attempts = 0;
+                            self.user_id = Some(e.user.id.clone());
 
                             handle_event!(self, ready, e);
                         }
@@ -162,6 +563,9 @@ impl<S: Sync + Send> Client<S> {
                         }
                         // Guild
                         DispatchEvent::GuildCreate(e) => {
+                            if self.config.auto_request_members && e.large == Some(true) {
+                                self.request_all_members(e.id.as_str());
+                            }
                             handle_event!(self, guild_create, e);
                         }
                         DispatchEvent::GuildUpdate(e) => {
@@ -203,8 +607,15 @@ impl<S: Sync + Send> Client<S> {
                         DispatchEvent::GuildRoleDelete(e) => {
                             handle_event!(self, guild_role_delete, e);
                         }
+                        // Invite
+                        DispatchEvent::InviteCreate(e) => {
+                            handle_event!(self, invite_create, e);
+                        }
+                        DispatchEvent::InviteDelete(e) => {
+                            handle_event!(self, invite_delete, e);
+                        }
                         // Message
-                        DispatchEvent::MessageCreate(e) => {
+                        DispatchEvent::MessageCreate(e) if !self.is_ignored(&e) => {
                             handle_event!(self, message_create, e);
                         }
                         DispatchEvent::MessageUpdate(e) => {
@@ -238,46 +649,123 @@ impl<S: Sync + Send> Client<S> {
                         DispatchEvent::UserUpdate(e) => {
                             handle_event!(self, user_update, e);
                         }
+                        // Voice
+                        DispatchEvent::VoiceStateUpdate(e) => {
+                            handle_event!(self, voice_state_update, e);
+                        }
+                        DispatchEvent::VoiceServerUpdate(e) => {
+                            handle_event!(self, voice_server_update, e);
+                        }
+                        DispatchEvent::Resumed => {
+                            self.reconnect_attempts = 0;
+
+                            handle_event!(self, resumed, ());
+                        }
+                        DispatchEvent::Unknown { name, raw } => {
+                            let event = (name, raw);
+                            handle_event!(self, unknown_event, event);
+                        }
                         _ => {}
                     },
                     Event::Reconnect => {
-                        log::info!("Reconnected successfully!");
+                        log::info!("[shard {}] Reconnected successfully!", self.config.gateway_shard_id);
                     }
                     Event::InvalidSession(resumable) => {
                         self.session.set_resumable(resumable);
                     }
-                    Event::HeartbeatACK => log::info!("HeartbeatACK received"),
-                    Event::Close(error) => {
-                        log::error!("Error detected {}", error);
+                    Event::HeartbeatACK => log::info!("[shard {}] HeartbeatACK received", self.config.gateway_shard_id),
+                    Event::Raw(raw) => {
+                        handle_event!(self, raw_event, raw);
+                    }
+                    Event::Close(error, frame) => {
+                        match frame {
+                            Some(frame) => log::error!(
+                                "[shard {}] Gateway closed with code {} ({:?}): {}",
+                                self.config.gateway_shard_id,
+                                frame.code,
+                                frame.reason,
+                                error
+                            ),
+                            None => log::error!("[shard {}] Error detected {}", self.config.gateway_shard_id, error),
+                        }
+                        let unrecoverable = unrecoverable_error(&error);
+
+                        // The error handlers are awaited, so they finish before returning or reconnecting
+                        let error = Arc::new(error);
+                        for func in &self.handler.error {
+                            if let Err(e) = func(self.session.clone(), Arc::clone(&error)).await {
+                                log::error!("[shard {}] Error handler error: {:?}", self.config.gateway_shard_id, e);
+                            }
+                        }
 
                         // Return if there are unrecoverable errors
-                        match &error {
-                            PandaError::AuthenticationFailed
-                            | PandaError::InvalidApiGatewayVersion
-                            | PandaError::InvalidShard
-                            | PandaError::ShardingRequired => return Err(error),
-                            _ => {}
+                        if let Some(error) = unrecoverable {
+                            return Err(error);
                         }
+
+                        // The session can't be resumed after these close codes, so the next
+                        // connection sends a new IDENTIFY
+                        if let PandaError::InvalidSequence | PandaError::SessionTimeout = *error {
+                            self.session.set_resumable(false);
+                        }
+
                         // If there was a recoverable error, try to reconnect
                         self.reconnect().await;
                     }
-                    event => log::info!("Unhandled event received: {:?}", event),
+                    event => log::info!(
+                        "[shard {}] Unhandled event received: {:?}",
+                        self.config.gateway_shard_id,
+                        event
+                    ),
                 };
             }
         }
 
-        //Ok(())
+        self.shutdown().await;
+
+        Ok(())
+    }
+
+    /// Sends a close frame to the gateway and closes the channels, the heartbeater stops when
+    /// the channel is closed
+    async fn shutdown(&mut self) {
+        if let Err(e) = self.gateway.to_gateway.send(Command::Close).await {
+            log::error!(
+                "[shard {}] Couldn't send the close frame to the gateway: {}",
+                self.config.gateway_shard_id,
+                e
+            );
+        }
+
+        if let Err(e) = self.gateway.close_channels() {
+            log::error!(
+                "[shard {}] Error when trying to close gateway channels at shutdown: {}",
+                self.config.gateway_shard_id,
+                e
+            );
+        }
+
+        log::info!("[shard {}] Client shutdown", self.config.gateway_shard_id);
     }
 
     /// Makes all necessary to reconnect to gateway
     async fn reconnect(&mut self) {
+        handle_event!(self, reconnecting, ());
+
         // Close channels
         if let Err(e) = self.gateway.close_channels() {
-            log::error!("Error when trying to close gateway channels at reconnect: {}", e);
+            log::error!(
+                "[shard {}] Error when trying to close gateway channels at reconnect: {}",
+                self.config.gateway_shard_id,
+                e
+            );
         };
 
         // Reconnect and get last sequence received, needed to send a RESUME command
-        let last_sequence = self.gateway.reconnect().await;
+        let last_sequence = self.gateway.reconnect(&mut self.reconnect_attempts).await;
+
+        // The commands sent with the session go to the new connection
+        self.session.set_to_gateway_ch(self.gateway.to_gateway.clone()).await;
 
         // If SessionData is resumable, send a RESUME command
         if self.session.is_resumable() {
@@ -290,15 +778,29 @@ impl<S: Sync + Send> Client<S> {
         }
     }
 
+    /// Sends a REQUEST_GUILD_MEMBERS command for all the members of the guild, it's skipped if
+    /// the GUILD_MEMBERS intent isn't set, because Discord closes the connection in that case
+    fn request_all_members(&self, guild_id: &str) {
+        let shard_id = self.config.gateway_shard_id;
+
+        if !self.config.gateway_intents.contains(Intents::GUILD_MEMBERS) {
+            log::warn!(
+                "[shard {}] The members of guild {} weren't requested, the GUILD_MEMBERS intent isn't set",
+                shard_id,
+                guild_id
+            );
+            return;
+        }
+
+        let cmd = Command::new_request_guild_members(guild_id, None, 0, false, None);
+        if let Err(e) = self.gateway.to_gateway.unbounded_send(cmd) {
+            log::error!("[shard {}] Error requesting the guild members: {}", shard_id, e);
+        }
+    }
+
     async fn clean_connect(&mut self) {
         // Create IDENTIFY
-        let shard = [self.config.gateway_shard_id, self.config.gateway_num_shards];
-        let identify = Command::new_identify(
-            &self.token,
-            self.config.gateway_large_treshold,
-            self.config.gateway_guilds_subscriptions,
-            shard,
-        );
+        let identify = self.config.identify(&self.token);
 
         // Send IDENTIFY, this should not fail
         self.gateway
@@ -314,7 +816,12 @@ impl<S: Sync + Send> Client<S> {
     async fn resume_connect(&mut self, last_sequence: Option<u64>) {
         // Create a RESUME command
         let token = self.token.clone();
-        let session_id = self.session.id().await;
+        let session_id = self.session.session_id().await;
+        log::info!(
+            "[shard {}] Resuming session {}",
+            self.config.gateway_shard_id,
+            session_id
+        );
         let resume = Command::new_resume(token, session_id, last_sequence);
 
         // Send RESUME, this should not fail
@@ -328,192 +835,40 @@ impl<S: Sync + Send> Client<S> {
         self.spawn_heartbeater();
     }
 
+    /// Returns true if the message is ignored by the `ignore_bots` and `ignore_self` configs
+    fn is_ignored(&self, msg: &Message) -> bool {
+        (self.config.ignore_bots && msg.author.bot)
+            || (self.config.ignore_self && self.user_id.as_ref() == Some(&msg.author.id))
+    }
+
     /// This function spawn a heartbeater that will be closed when
     /// the current gateway channel is close.
     fn spawn_heartbeater(&self) {
         let heartbeat_interval = self.gateway.heartbeat_interval;
         let to_gateway = self.gateway.to_gateway.clone();
+        let heartbeat_ack = Arc::clone(&self.gateway.heartbeat_ack);
+        let shard_id = self.config.gateway_shard_id;
 
         runtime::spawn(async move {
-            heartbeat::heartbeater(heartbeat_interval, to_gateway).await;
-            log::info!("spawn_heartbeater exited");
+            heartbeat::heartbeater(heartbeat_interval, to_gateway, heartbeat_ack, shard_id).await;
+            log::info!("[shard {}] spawn_heartbeater exited", shard_id);
         });
     }
 
     // Implementations of all on_DiscordEvent
-    impl_on_event_fn! {
-        /// Set the handler function for [`Ready`] event
-        ///
-        /// [`Ready`]: ../models/gateway/events/struct.Ready.html
-        pub fn on_ready(ready, Ready);
-
-
-        // *******************************************************************************
-        // * CHANNEL METHODS
-        // *******************************************************************************
-
-
-        /// Set the handler function for [`ChannelCreate`] event
-        ///
-        /// [`ChannelCreate`]: ../models/gateway/events/struct.ChannelCreate.html
-        pub fn on_channel_create(channel_create, ChannelCreate);
-
-        /// Set the handler function for [`ChannelUpdate`] event
-        ///
-        /// [`ChannelUpdate`]: ../models/gateway/events/struct.ChannelUpdate.html
-        pub fn on_channel_update(channel_update, ChannelUpdate);
-
-        /// Set the handler function for [`ChannelDelete`] event
-        ///
-        /// [`ChannelDelete`]: ../models/gateway/events/struct.ChannelDelete.html
-        pub fn on_channel_delete(channel_delete, ChannelDelete);
-
-        /// Set the handler function for [`ChannelPinsUpdate`] event
-        ///
-        /// [`ChannelPinsUpdate`]: ../models/gateway/events/struct.ChannelPinsUpdate.html
-        pub fn on_channel_pins_update(channel_pins_update, ChannelPinsUpdate);
-
-
-        // *******************************************************************************
-        // * GUILD METHODS
-        // *******************************************************************************
-
-
-        /// Set the handler function for [`GuildCreate`] event
-        ///
-        /// [`GuildCreate`]: ../models/gateway/events/struct.GuildCreate.html
-        pub fn on_guild_create(guild_create, GuildCreate);
-
-        /// Set the handler function for [`GuildUpdate`] event
-        ///
-        /// [`GuildUpdate`]: ../models/gateway/events/struct.GuildUpdate.html
-        pub fn on_guild_update(guild_update, GuildUpdate);
-
-        /// Set the handler function for [`GuildDelete`] event
-        ///
-        /// [`GuildDelete`]: ../models/gateway/events/struct.GuildDelete.html
-        pub fn on_guild_delete(guild_delete, GuildDelete);
-
-        /// Set the handler function for [`GuildBanAdd`] event
-        ///
-        /// [`GuildBanAdd`]: ../models/gateway/events/struct.GuildBanAdd.html
-        pub fn on_guild_ban_add(guild_ban_add, GuildBanAdd);
-
-        /// Set the handler function for [`GuildBanRemove`] event
-        ///
-        /// [`GuildBanRemove`]: ../models/gateway/events/struct.GuildBanRemove.html
-        pub fn on_guild_ban_remove(guild_ban_remove, GuildBanRemove);
-
-        /// Set the handler function for [`GuildEmojisUpdate`] event
-        ///
-        /// [`GuildEmojisUpdate`]: ../models/gateway/events/struct.GuildEmojisUpdate.html
-        pub fn on_guild_emojis_update(guild_emojis_update, GuildEmojisUpdate);
-
-        /// Set the handler function for [`GuildIntegrationsUpdate`] event
-        ///
-        /// [`GuildIntegrationsUpdate`]: ../models/gateway/events/struct.GuildIntegrationsUpdate.html
-        pub fn on_guild_integrations_update(guild_integrations_update, GuildIntegrationsUpdate);
-
-        /// Set the handler function for [`GuildMemberAdd`] event
-        ///
-        /// [`GuildMemberAdd`]: ../models/gateway/events/struct.GuildMemberAdd.html
-        pub fn on_guild_member_add(guild_member_add, GuildMemberAdd);
-
-        /// Set the handler function for [`GuildMemberUpdate`] event
-        ///
-        /// [`GuildMemberUpdate`]: ../models/gateway/events/struct.GuildMemberUpdate.html
-        pub fn on_guild_member_update(guild_member_update, GuildMemberUpdate);
-
-        /// Set the handler function for [`GuildMemberRemove`] event
-        ///
-        /// [`GuildMemberRemove`]: ../models/gateway/events/struct.GuildMemberRemove.html
-        pub fn on_guild_member_remove(guild_member_remove, GuildMemberRemove);
-
-        /// Set the handler function for [`GuildMembersChunk`] event
-        ///
-        /// [`GuildMembersChunk`]: ../models/gateway/events/struct.GuildMembersChunk.html
-        pub fn on_guild_members_chunk(guild_members_chunk, GuildMembersChunk);
-
-        /// Set the handler function for [`GuildRoleCreate`] event
-        ///
-        /// [`GuildRoleCreate`]: ../models/gateway/events/struct.GuildRoleCreate.html
-        pub fn on_guild_role_create(guild_role_create, GuildRoleCreate);
-
-        /// Set the handler function for [`GuildRoleUpdate`] event
-        ///
-        /// [`GuildRoleUpdate`]: ../models/gateway/events/struct.GuildRoleUpdate.html
-        pub fn on_guild_role_update(guild_role_update, GuildRoleUpdate);
-
-        /// Set the handler function for [`GuildRoleDelete`] event
-        ///
-        /// [`GuildRoleDelete`]: ../models/gateway/events/struct.GuildRoleDelete.html
-        pub fn on_guild_role_delete(guild_role_delete, GuildRoleDelete);
-
-
-        // *******************************************************************************
-        // * MESSAGE METHODS
-        // *******************************************************************************
-
-
-        /// Set the handler function for [`MessageCreate`] event
-        ///
-        /// [`MessageCreate`]: ../models/gateway/events/struct.MessageCreate.html
-        pub fn on_message_create(message_create, MessageCreate);
-
-        /// Set the handler function for [`MessageUpdate`] event
-        ///
-        /// [`MessageUpdate`]: ../models/gateway/events/struct.MessageUpdate.html
-        pub fn on_message_update(message_update, MessageUpdate);
-
-        /// Set the handler function for [`MessageDelete`] event
-        ///
-        /// [`MessageDelete`]: ../models/gateway/events/struct.MessageDelete.html
-        pub fn on_message_delete(message_delete, MessageDelete);
-
-        /// Set the handler function for [`MessageDeleteBulk`] event
-        ///
-        /// [`MessageDeleteBulk`]: ../models/gateway/events/struct.MessageDeleteBulk.html
-        pub fn on_message_delete_bulk(message_delete_bulk, MessageDeleteBulk);
-
-        /// Set the handler function for [`MessageReactionAdd`] event
-        ///
-        /// [`MessageReactionAdd`]: ../models/gateway/events/struct.MessageReactionAdd.html
-        pub fn on_message_reaction_add(message_reaction_add, MessageReactionAdd);
-
-        /// Set the handler function for [`MessageReactionRemove`] event
-        ///
-        /// [`MessageReactionRemove`]: ../models/gateway/events/struct.MessageReactionRemove.html
-        pub fn on_message_reaction_remove(message_reaction_remove, MessageReactionRemove);
-
-        /// Set the handler function for [`MessageReactionRemoveAll`] event
-        ///
-        /// [`MessageReactionRemoveAll`]: ../models/gateway/events/struct.MessageReactionRemoveAll.html
-        pub fn on_message_reaction_remove_all(message_reaction_remove_all, MessageReactionRemoveAll);
-
-        /// Set the handler function for [`MessageReactionRemoveEmoji`] event
-        ///
-        /// [`MessageReactionRemoveEmoji`]: ../models/gateway/events/struct.MessageReactionRemoveEmoji.html
-        pub fn on_message_reaction_remove_emoji(message_reaction_remove_emoji, MessageReactionRemoveEmoji);
-
-
-        // *******************************************************************************
-        // * PRESENCE METHODS
-        // *******************************************************************************
-
-
-        /// Set the handler function for [`PresenceUpdate`] event
-        ///
-        /// [`PresenceUpdate`]: ../models/gateway/events/struct.PresenceUpdate.html
-        pub fn on_presence_update(presence_update, PresenceUpdate);
-
-        /// Set the handler function for [`TypingStart`] event
-        ///
-        /// [`TypingStart`]: ../models/gateway/events/struct.TypingStart.html
-        pub fn on_typing_start(typing_start, TypingStart);
+    impl_event_handler_methods!();
+}
 
-        /// Set the handler function for [`UserUpdate`] event
-        ///
-        /// [`UserUpdate`]: ../models/gateway/events/struct.UserUpdate.html
-        pub fn on_user_update(user_update, UserUpdate)
+/// Returns a copy of the error if it can't be recovered by reconnecting, these errors are unit
+/// variants so one can be passed to the error handler and the other returned by `start`
+fn unrecoverable_error(error: &PandaError) -> Option<PandaError> {
+    match error {
+        PandaError::AuthenticationFailed => Some(PandaError::AuthenticationFailed),
+        PandaError::InvalidApiGatewayVersion => Some(PandaError::InvalidApiGatewayVersion),
+        PandaError::InvalidShard => Some(PandaError::InvalidShard),
+        PandaError::ShardingRequired => Some(PandaError::ShardingRequired),
+        PandaError::InvalidIntents => Some(PandaError::InvalidIntents),
+        PandaError::DisallowedIntents => Some(PandaError::DisallowedIntents),
+        _ => None,
     }
 }
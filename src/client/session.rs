@@ -1,11 +1,20 @@
 //! SessionData
 
+use super::cache::Cache;
 use crate::{
     error::{Result, PandaError},
-    models::{ gateway::commands::Command, user::StatusUpdate },
+    models::{
+        channel::{Attachment, Channel},
+        gateway::commands::Command,
+        guild::{Guild, GuildMember},
+        user::{StatusUpdate, User},
+    },
     HttpClient};
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
 
 use futures::{ channel::mpsc::UnboundedSender, lock::Mutex, sink::SinkExt };
 
@@ -13,19 +22,38 @@ use futures::{ channel::mpsc::UnboundedSender, lock::Mutex, sink::SinkExt };
 pub struct SessionData<S> {
     id: Mutex<String>,
     pub http: HttpClient,
+    /// The state of the bot, given with [`Client::new_with_state`] or [`ClientBuilder.state`].
+    /// The SessionData is kept between reconnections, so the state is never lost, use a type
+    /// with interior mutability (like `Mutex<T>`) to modify it from the handlers.
+    ///
+    /// [`Client::new_with_state`]: struct.Client.html#method.new_with_state
+    /// [`ClientBuilder.state`]: struct.ClientBuilder.html#method.state
     pub state: S,
     is_resumable: AtomicBool,
-    to_gateway_ch: Mutex<UnboundedSender<Command>>
+    last_sequence: Arc<AtomicU64>,
+    to_gateway_ch: Mutex<UnboundedSender<Command>>,
+    shutdown_tx: UnboundedSender<()>,
+    pub(crate) cache: Option<Cache>,
 }
 
 impl<S> SessionData<S> {
-    pub(crate) fn new(token: String, state: S, to_gateway_ch: UnboundedSender<Command>) -> Self {
+    pub(crate) fn new(
+        http: HttpClient,
+        state: S,
+        to_gateway_ch: UnboundedSender<Command>,
+        last_sequence: Arc<AtomicU64>,
+        shutdown_tx: UnboundedSender<()>,
+        cache: Option<Cache>,
+    ) -> Self {
         SessionData {
             id: Mutex::new("".into()),
-            http: HttpClient::new(token),
+            http,
             state,
             is_resumable: AtomicBool::new(true),
-            to_gateway_ch: Mutex::new(to_gateway_ch)
+            last_sequence,
+            to_gateway_ch: Mutex::new(to_gateway_ch),
+            shutdown_tx,
+            cache,
         }
     }
 
@@ -39,26 +67,214 @@ impl<S> SessionData<S> {
         self.is_resumable.load(Ordering::Relaxed)
     }
 
+    /// Set the channel used to send the commands, each gateway connection has its own channel,
+    /// so it must be updated after reconnecting
+    pub(crate) async fn set_to_gateway_ch(&self, to_gateway_ch: UnboundedSender<Command>) {
+        *self.to_gateway_ch.lock().await = to_gateway_ch;
+    }
+
     /// Set the value to id field
     pub(crate) async fn set_id(&self, id: String) {
         let mut session_id = self.id.lock().await;
         *session_id = id;
     }
 
-    /// Get the value to id field
-    pub(crate) async fn id(&self) -> String {
+    /// Get the session id, it's received in the READY event and used to RESUME the session.
+    /// It's empty until the READY event is received
+    pub async fn session_id(&self) -> String {
         let session_id = self.id.lock().await;
         session_id.clone()
     }
 
-    //Send
+    /// Get the sequence of the last event received from the gateway, it's None if no event was
+    /// received yet in the current connection
+    pub fn last_sequence(&self) -> Option<u64> {
+        match self.last_sequence.load(Ordering::Relaxed) {
+            0 => None,
+            seq => Some(seq),
+        }
+    }
+
+    /// Returns the cached [`Guild`], it's None if it's not cached or the cache is disabled
+    ///
+    /// [`Guild`]: ../models/guild/struct.Guild.html
+    pub async fn cache_guild(&self, guild_id: impl AsRef<str>) -> Option<Guild> {
+        self.cache.as_ref()?.guild(guild_id.as_ref()).await
+    }
+
+    /// Returns the cached [`Channel`], it's None if it's not cached or the cache is disabled
+    ///
+    /// [`Channel`]: ../models/channel/struct.Channel.html
+    pub async fn cache_channel(&self, channel_id: impl AsRef<str>) -> Option<Channel> {
+        self.cache.as_ref()?.channel(channel_id.as_ref()).await
+    }
+
+    /// Returns the [`Channel`] from the cache, or requests it with [`HttpClient.get_channel`] if
+    /// it isn't cached, and the requested channel is added to the cache. Use
+    /// [`force_fetch_channel`] to always request it.
+    ///
+    /// [`Channel`]: ../models/channel/struct.Channel.html
+    /// [`HttpClient.get_channel`]: ../struct.HttpClient.html#method.get_channel
+    /// [`force_fetch_channel`]: struct.SessionData.html#method.force_fetch_channel
+    pub async fn channel(&self, channel_id: impl AsRef<str>) -> Result<Channel> {
+        match self.cache_channel(channel_id.as_ref()).await {
+            Some(channel) => Ok(channel),
+            None => self.force_fetch_channel(channel_id).await,
+        }
+    }
+
+    /// Requests the [`Channel`] with [`HttpClient.get_channel`] without checking the cache, the
+    /// cached channel is updated with it
+    ///
+    /// [`Channel`]: ../models/channel/struct.Channel.html
+    /// [`HttpClient.get_channel`]: ../struct.HttpClient.html#method.get_channel
+    pub async fn force_fetch_channel(&self, channel_id: impl AsRef<str>) -> Result<Channel> {
+        let channel = self.http.get_channel(channel_id).await?;
+
+        if let Some(cache) = &self.cache {
+            cache.insert_channel(&channel).await;
+        }
+
+        Ok(channel)
+    }
+
+    /// Returns the cached [`User`], it's None if it's not cached or the cache is disabled
+    ///
+    /// [`User`]: ../models/user/struct.User.html
+    pub async fn cache_user(&self, user_id: impl AsRef<str>) -> Option<User> {
+        self.cache.as_ref()?.user(user_id.as_ref()).await
+    }
+
+    /// Returns the cached [`GuildMember`], it's None if it's not cached or the cache is disabled
+    ///
+    /// [`GuildMember`]: ../models/guild/struct.GuildMember.html
+    pub async fn cache_member(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Option<GuildMember> {
+        self.cache.as_ref()?.member(guild_id.as_ref(), user_id.as_ref()).await
+    }
+
+    /// Returns true if the user reacted with the given emoji to the message, see
+    /// [`HttpClient.has_reacted`]
+    ///
+    /// [`HttpClient.has_reacted`]: ../struct.HttpClient.html#method.has_reacted
+    pub async fn has_reacted(
+        &self,
+        channel_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
+        emoji: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<bool> {
+        self.http.has_reacted(channel_id, message_id, emoji, user_id).await
+    }
+
+    /// Returns the guild members whose username or nickname starts with `query`, see
+    /// [`HttpClient.search_guild_members`]
+    ///
+    /// [`HttpClient.search_guild_members`]: ../struct.HttpClient.html#method.search_guild_members
+    pub async fn search_guild_members(
+        &self,
+        guild_id: impl AsRef<str>,
+        query: &str,
+        limit: u16,
+    ) -> Result<Vec<GuildMember>> {
+        self.http.search_guild_members(guild_id, query, limit).await
+    }
+
+    /// Downloads the file of an [`Attachment`], see [`HttpClient.download_attachment`]
+    ///
+    /// [`Attachment`]: ../models/channel/struct.Attachment.html
+    /// [`HttpClient.download_attachment`]: ../struct.HttpClient.html#method.download_attachment
+    pub async fn download_attachment(&self, attachment: &Attachment) -> Result<Vec<u8>> {
+        self.http.download_attachment(attachment).await
+    }
+
+    /// Stops the client, it closes the gateway connection and [`Client.start`] returns `Ok(())`
+    ///
+    /// [`Client.start`]: struct.Client.html#method.start
+    pub fn shutdown(&self) {
+        // It only fails if the client was dropped, so there is nothing to stop
+        let _ = self.shutdown_tx.unbounded_send(());
+    }
+
+    /// Sends a STATUS_UPDATE command to the gateway, it updates the presence of the bot
     pub async fn update_status(&self, status_update: StatusUpdate) -> Result<()> {
 
         let cmd = Command::new_status_update(status_update);
 
+        self.send_command(cmd).await
+    }
+
+    /// Sends a REQUEST_GUILD_MEMBERS command to the gateway, the members are received in
+    /// [`GuildMembersChunk`] events. Use `query` to request the members whose username starts
+    /// with it, or `user_ids` to request specific members, if both are None all the members are
+    /// requested. `limit` is the maximum number of members returned, 0 means no limit when
+    /// requesting all the members. Requesting all the members requires the GUILD_MEMBERS intent.
+    ///
+    /// [`GuildMembersChunk`]: ../models/gateway/events/struct.GuildMembersChunk.html
+    pub async fn request_guild_members(
+        &self,
+        guild_id: impl Into<String>,
+        query: Option<String>,
+        limit: u64,
+        presences: bool,
+        user_ids: Option<Vec<String>>,
+    ) -> Result<()> {
+        let cmd = Command::new_request_guild_members(guild_id, query, limit, presences, user_ids);
+
+        self.send_command(cmd).await
+    }
+
+    /// Sends a VOICE_STATE_UPDATE command to the gateway, it joins, moves or leaves (when
+    /// `channel_id` is None) a voice channel of the guild. Discord answers with the
+    /// VOICE_STATE_UPDATE and VOICE_SERVER_UPDATE events needed to connect to the voice server.
+    pub async fn update_voice_state(
+        &self,
+        guild_id: impl Into<String>,
+        channel_id: Option<String>,
+        self_mute: bool,
+        self_deaf: bool,
+    ) -> Result<()> {
+        let cmd = Command::new_voice_state_update(guild_id, channel_id, self_mute, self_deaf);
+
+        self.send_command(cmd).await
+    }
+
+    async fn send_command(&self, cmd: Command) -> Result<()> {
         // TODO: Into<Error>
         self.to_gateway_ch.lock().await.send(cmd).await.map_err(|_| PandaError::ConnectionClosed)?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime;
+    use futures::{channel::mpsc, stream::StreamExt};
+
+    #[test]
+    fn commands_are_sent_to_the_new_connection() {
+        let (old_tx, mut old_rx) = mpsc::unbounded();
+        let (shutdown_tx, _shutdown_rx) = mpsc::unbounded();
+        let session = SessionData::new(
+            HttpClient::new("token"),
+            (),
+            old_tx,
+            Arc::new(AtomicU64::new(0)),
+            shutdown_tx,
+            None,
+        );
+
+        runtime::block_on(async {
+            // The old connection is closed when reconnecting, and a new one is created
+            old_rx.close();
+            let (new_tx, mut new_rx) = mpsc::unbounded();
+            session.set_to_gateway_ch(new_tx).await;
+
+            session.send_command(Command::new_heartbeat()).await.unwrap();
+
+            assert_eq!(new_rx.next().await, Some(Command::new_heartbeat()));
+            assert_eq!(old_rx.next().await, None);
+        });
+    }
+}
@@ -0,0 +1,157 @@
+use super::{config::ConfigBuilder, event_handler, handler::EventHandlers, Client, Config, EventHandler, SessionData};
+use crate::{
+    error::{PandaError, Result},
+    models::gateway::events::*,
+    runtime, HttpClient,
+};
+
+use futures::{future, FutureExt};
+use std::{future::Future, sync::Arc, time::Duration};
+
+/// ShardManager runs many shards of the same bot, each shard is a [`Client`] connected with its
+/// own shard id, and all of them use the handlers set in the ShardManager.
+///
+/// ```no_run
+/// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+/// use panda::client::ShardManager;
+///
+/// let mut manager = ShardManager::new("your token here", 4);
+///
+/// manager.on_message_create(|s, msg| async move {
+///     if msg.content == "!ping" {
+///         msg.send(&s.http, "!pong").await?;
+///     }
+///     Ok(())
+/// });
+///
+/// manager.start().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: struct.Client.html
+pub struct ShardManager<S> {
+    handler: EventHandlers<S>,
+    config: ConfigBuilder,
+    token: String,
+    state: S,
+    num_shards: u64,
+    max_concurrency: Option<u64>,
+}
+
+impl ShardManager<()> {
+    /// Create a new ShardManager with the given number of shards
+    pub fn new(token: impl Into<String>, num_shards: u64) -> Self {
+        ShardManager::new_with_state(token, num_shards, ())
+    }
+}
+
+impl<S: Clone + Sync + Send + 'static> ShardManager<S> {
+    /// Create a new ShardManager with state, each shard receives a clone of it, so use a type
+    /// like `Arc<T>` to share it between shards
+    pub fn new_with_state(token: impl Into<String>, num_shards: u64, state: S) -> Self {
+        // Add Bot prefix to the token if it doesn't have, it's used by the HttpClient
+        let mut token = token.into();
+        if !token.starts_with("Bot ") {
+            token.insert_str(0, "Bot ");
+        }
+
+        ShardManager {
+            handler: EventHandlers::new(),
+            config: Config::new(),
+            token,
+            state,
+            num_shards,
+            max_concurrency: None,
+        }
+    }
+
+    /// Set the configs used by all the shards, the shard set in the config is ignored
+    pub fn set_config(&mut self, config: ConfigBuilder) {
+        self.config = config;
+    }
+
+    /// Set how many shards can connect at the same time, Discord only allows `max_concurrency`
+    /// IDENTIFY every 5 seconds. By default it's the `max_concurrency` returned by
+    /// [`HttpClient.get_gateway_bot`].
+    ///
+    /// [`HttpClient.get_gateway_bot`]: ../struct.HttpClient.html#method.get_gateway_bot
+    pub fn set_max_concurrency(&mut self, max_concurrency: u64) {
+        self.max_concurrency = Some(max_concurrency.max(1));
+    }
+
+    /// Connect all the shards and start them, it returns when all the shards stop or with the
+    /// first shard error, in that case the other shards are stopped
+    pub async fn start(self) -> Result<()> {
+        if self.num_shards == 0 {
+            return Err(PandaError::InvalidInput("the number of shards can't be 0".into()));
+        }
+
+        // All the shards share the same HttpClient, so they share the rate limits
+        let http = match &self.config.http_client {
+            Some(http) => http.clone(),
+            None => {
+                let mut http = HttpClient::new_with_timeout(&self.token, self.config.http_timeout);
+                http.set_api_url(self.config.api_url.as_str());
+                http
+            }
+        };
+
+        let max_concurrency = match self.max_concurrency {
+            Some(max_concurrency) => max_concurrency,
+            None => {
+                let gateway_bot = http.get_gateway_bot().await?;
+                gateway_bot.session_start_limit.max_concurrency.max(1)
+            }
+        };
+
+        let mut shards = Vec::with_capacity(self.num_shards as usize);
+        // The sessions are kept to stop the running shards if another one fails
+        let mut sessions = Vec::with_capacity(self.num_shards as usize);
+
+        for shard_id in 0..self.num_shards {
+            // Wait before starting the next group of shards
+            if shard_id > 0 && shard_id % max_concurrency == 0 {
+                runtime::sleep(Duration::from_secs(5)).await;
+            }
+
+            let config = self
+                .config
+                .clone()
+                .set_shards(shard_id, self.num_shards)
+                .set_http_client(http.clone())
+                .build();
+            let mut client = match Client::new_with_state_and_config(&self.token, self.state.clone(), config).await {
+                Ok(client) => client,
+                Err(e) => {
+                    shutdown_all(&sessions);
+                    return Err(e);
+                }
+            };
+            client.handler = self.handler.clone();
+            sessions.push(Arc::clone(&client.session));
+
+            log::info!("Shard {} connected", shard_id);
+
+            shards.push(runtime::spawn(async move { client.start().await }));
+        }
+
+        // Returns as soon as any shard fails, not in the order they were started
+        if let Err(e) = future::try_join_all(shards.into_iter().map(|shard| async move { shard.await? })).await {
+            shutdown_all(&sessions);
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    // Implementations of all on_DiscordEvent
+    impl_event_handler_methods!();
+}
+
+/// Stops the shards of the given sessions, the shards that already stopped are ignored
+fn shutdown_all<S>(sessions: &[Arc<SessionData<S>>]) {
+    for session in sessions {
+        session.shutdown();
+    }
+}
@@ -45,6 +45,9 @@ pub enum PandaError {
     /// Discord API
     HttpNoResponse,
 
+    /// Returned when Discord HTTP API didn't respond in the configured timeout
+    Timeout,
+
     /// Returned when http request format was invalid
     HttpImproperlyFormatted,
 
@@ -58,6 +61,17 @@ pub enum PandaError {
     /// such as wrong {channel_id}
     HttpInvalidParameters,
 
+    /// Returned when Discord API responds with an error, it contains the
+    /// [JSON error code](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json)
+    /// and the message sent by Discord
+    Api {
+        code: u64,
+        message: String,
+    },
+
+    /// Returned when a function receives an invalid argument, it contains the reason
+    InvalidInput(String),
+
     /// Returned when the gateway couldn't close the connection succesfully
     UnsuccessfulConnectionClose,
 
@@ -70,9 +84,31 @@ pub enum PandaError {
     // Invalid API version (gateway)
     InvalidApiGatewayVersion,
 
+    /// Returned when the gateway closes the connection because the sequence sent in a RESUME
+    /// was invalid, the next connection sends a new IDENTIFY
+    InvalidSequence,
+
+    /// Returned when the gateway closes the connection because the payloads were sent too fast
+    RateLimited,
+
+    /// Returned when the gateway closes the connection because the session timed out, the next
+    /// connection sends a new IDENTIFY
+    SessionTimeout,
+
+    /// Returned when the IDENTIFY was sent with invalid intents
+    InvalidIntents,
+
+    /// Returned when the IDENTIFY was sent with privileged intents that aren't enabled for
+    /// the bot
+    DisallowedIntents,
+
     /// serde_json
     SerdeError(serde_json::Error),
 
+    /// Returned when the body of a Discord HTTP API response couldn't be
+    /// deserialized into the expected model
+    Deserialize(serde_json::Error),
+
     /// tungstenite
     TungsteniteError(TungsteniteError),
 
@@ -90,15 +126,24 @@ impl fmt::Display for PandaError {
             Self::UnexpectedPayloadReceived => write!(f, "Unexpected payload received"),
             Self::WrongCompression => write!(f, "Wrong zlib compression"),
             Self::HttpNoResponse => write!(f, "Discord HTTP API didn't response"),
+            Self::Timeout => write!(f, "Discord HTTP API didn't respond in time"),
             Self::HttpImproperlyFormatted => write!(f, "Invalid format of request body"),
             Self::HttpUnauthorized => write!(f, "The client token is invalid"),
             Self::HttpForbidden => write!(f, "The client did not have permission to the resource"),
             Self::HttpInvalidParameters => write!(f, "The request had invalid parameters"),
+            Self::Api { code, message } => write!(f, "Discord API error {}: {}", code, message),
+            Self::InvalidInput(reason) => write!(f, "Invalid input: {}", reason),
             Self::UnsuccessfulConnectionClose => write!(f, "The gateway couldn't close succesfully the connection"),
             Self::InvalidShard => write!(f, "You sent an invalid shard"),
             Self::ShardingRequired => write!(f, "The SessionData would have handled too many guilds - you are required to shard your connection in order to connect."),
             Self::InvalidApiGatewayVersion => write!(f, "panda needs to update the gateway version"),
+            Self::InvalidSequence => write!(f, "Invalid sequence sent when resuming"),
+            Self::RateLimited => write!(f, "The gateway payloads were sent too fast"),
+            Self::SessionTimeout => write!(f, "The gateway session timed out"),
+            Self::InvalidIntents => write!(f, "Invalid intents sent"),
+            Self::DisallowedIntents => write!(f, "The intents sent aren't enabled or allowed for the bot"),
             Self::SerdeError(e) => write!(f, "Serde Error: {}", e),
+            Self::Deserialize(e) => write!(f, "Couldn't deserialize the response body: {}", e),
             Self::TungsteniteError(e) => write!(f, "Tungstenite Error: {}", e),
             Self::UnknownOpcodeSent => write!(f, "panda sent an invalid Opcode, please report the bug"),
             Self::InvalidDecodeSent => write!(f, "panda sent an invalid payload, please report the bug"),
@@ -134,9 +179,12 @@ impl From<TungsteniteError> for PandaError {
 }
 
 impl From<isahc::Error> for PandaError {
-    fn from(_error: isahc::Error) -> Self {
+    fn from(error: isahc::Error) -> Self {
         // TODO: add match
-        PandaError::HttpNoResponse
+        match error {
+            isahc::Error::Timeout => PandaError::Timeout,
+            _ => PandaError::HttpNoResponse,
+        }
     }
 }
 
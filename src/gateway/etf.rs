@@ -0,0 +1,377 @@
+//! Erlang External Term Format, used by the gateway with `encoding=etf`.
+//! The terms are converted from/to serde_json values, so the payloads are deserialized
+//! the same way as with JSON encoding.
+
+use crate::error::{PandaError, Result};
+
+use flate2::read::ZlibDecoder;
+use serde_json::{Map, Number, Value};
+use std::{convert::TryInto, io::Read};
+
+const VERSION: u8 = 131;
+
+const NEW_FLOAT_EXT: u8 = 70;
+const COMPRESSED: u8 = 80;
+const SMALL_INTEGER_EXT: u8 = 97;
+const INTEGER_EXT: u8 = 98;
+const FLOAT_EXT: u8 = 99;
+const ATOM_EXT: u8 = 100;
+const SMALL_TUPLE_EXT: u8 = 104;
+const LARGE_TUPLE_EXT: u8 = 105;
+const NIL_EXT: u8 = 106;
+const STRING_EXT: u8 = 107;
+const LIST_EXT: u8 = 108;
+const BINARY_EXT: u8 = 109;
+const SMALL_BIG_EXT: u8 = 110;
+const LARGE_BIG_EXT: u8 = 111;
+const SMALL_ATOM_EXT: u8 = 115;
+const MAP_EXT: u8 = 116;
+const ATOM_UTF8_EXT: u8 = 118;
+const SMALL_ATOM_UTF8_EXT: u8 = 119;
+
+// Integers bigger than this can't be represented exactly in JSON, Discord snowflakes are always
+// bigger, so they are converted to strings like in the JSON encoding
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Decodes an ETF message into a serde_json Value
+pub(crate) fn decode(data: &[u8]) -> Result<Value> {
+    let mut decoder = Decoder { data, pos: 0 };
+
+    if decoder.read_u8()? != VERSION {
+        return Err(PandaError::UnknownPayloadReceived);
+    }
+
+    decoder.read_term()
+}
+
+/// Encodes a serde_json Value into an ETF message
+pub(crate) fn encode(value: &Value) -> Vec<u8> {
+    let mut buffer = vec![VERSION];
+    write_term(&mut buffer, value);
+
+    buffer
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.pos.checked_add(len).ok_or(PandaError::UnknownPayloadReceived)?;
+        let bytes = self.data.get(self.pos..end).ok_or(PandaError::UnknownPayloadReceived)?;
+        self.pos = end;
+
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.read_bytes(2)?.try_into().unwrap()))
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    fn read_string(&mut self, len: usize) -> Result<String> {
+        let bytes = self.read_bytes(len)?;
+
+        String::from_utf8(bytes.to_vec()).map_err(|_| PandaError::UnknownPayloadReceived)
+    }
+
+    fn read_term(&mut self) -> Result<Value> {
+        let value = match self.read_u8()? {
+            SMALL_INTEGER_EXT => Value::from(self.read_u8()?),
+            INTEGER_EXT => Value::from(self.read_u32()? as i32),
+            NEW_FLOAT_EXT => {
+                let float = f64::from_bits(u64::from_be_bytes(self.read_bytes(8)?.try_into().unwrap()));
+                Number::from_f64(float).map(Value::Number).unwrap_or(Value::Null)
+            }
+            FLOAT_EXT => {
+                let float = self.read_string(31)?;
+                let float = float.trim_end_matches('\0').parse::<f64>();
+                float
+                    .ok()
+                    .and_then(Number::from_f64)
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null)
+            }
+            ATOM_EXT | ATOM_UTF8_EXT => {
+                let len = self.read_u16()? as usize;
+                atom_to_value(self.read_string(len)?)
+            }
+            SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                let len = self.read_u8()? as usize;
+                atom_to_value(self.read_string(len)?)
+            }
+            SMALL_TUPLE_EXT => {
+                let arity = self.read_u8()? as usize;
+                self.read_array(arity)?
+            }
+            LARGE_TUPLE_EXT => {
+                let arity = self.read_u32()? as usize;
+                self.read_array(arity)?
+            }
+            NIL_EXT => Value::Array(Vec::new()),
+            STRING_EXT => {
+                // A list of small integers
+                let len = self.read_u16()? as usize;
+                Value::Array(self.read_bytes(len)?.iter().map(|b| Value::from(*b)).collect())
+            }
+            LIST_EXT => {
+                let len = self.read_u32()? as usize;
+                let list = self.read_array(len)?;
+
+                // Proper lists end with NIL_EXT, improper tails are ignored
+                self.read_term()?;
+
+                list
+            }
+            BINARY_EXT => {
+                let len = self.read_u32()? as usize;
+                Value::String(self.read_string(len)?)
+            }
+            SMALL_BIG_EXT => {
+                let len = self.read_u8()? as usize;
+                self.read_big(len)?
+            }
+            LARGE_BIG_EXT => {
+                let len = self.read_u32()? as usize;
+                self.read_big(len)?
+            }
+            MAP_EXT => {
+                let arity = self.read_u32()? as usize;
+                let mut map = Map::new();
+
+                for _ in 0..arity {
+                    let key = match self.read_term()? {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    map.insert(key, self.read_term()?);
+                }
+
+                Value::Object(map)
+            }
+            COMPRESSED => {
+                let size = self.read_u32()? as usize;
+                let compressed = self.read_bytes(self.data.len() - self.pos)?;
+
+                let mut data = Vec::with_capacity(size);
+                ZlibDecoder::new(compressed)
+                    .read_to_end(&mut data)
+                    .map_err(|_| PandaError::WrongCompression)?;
+
+                Decoder { data: &data, pos: 0 }.read_term()?
+            }
+            _ => return Err(PandaError::UnknownPayloadReceived),
+        };
+
+        Ok(value)
+    }
+
+    fn read_array(&mut self, len: usize) -> Result<Value> {
+        // The capacity is limited, the length could be wrong
+        let mut array = Vec::with_capacity(len.min(1024));
+
+        for _ in 0..len {
+            array.push(self.read_term()?);
+        }
+
+        Ok(Value::Array(array))
+    }
+
+    fn read_big(&mut self, len: usize) -> Result<Value> {
+        let negative = self.read_u8()? != 0;
+        let digits = self.read_bytes(len)?;
+
+        // Only 64 bits integers are supported
+        if len > 8 {
+            return Err(PandaError::UnknownPayloadReceived);
+        }
+
+        let value = digits
+            .iter()
+            .rev()
+            .fold(0u64, |value, digit| (value << 8) | u64::from(*digit));
+
+        let value = match (negative, value > MAX_SAFE_INTEGER) {
+            (false, true) => Value::String(value.to_string()),
+            (false, false) => Value::from(value),
+            (true, true) => Value::String(format!("-{}", value)),
+            (true, false) => Value::from(-(value as i64)),
+        };
+
+        Ok(value)
+    }
+}
+
+fn atom_to_value(atom: String) -> Value {
+    match atom.as_str() {
+        "nil" | "null" => Value::Null,
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => Value::String(atom),
+    }
+}
+
+fn write_atom(buffer: &mut Vec<u8>, atom: &str) {
+    buffer.push(SMALL_ATOM_UTF8_EXT);
+    buffer.push(atom.len() as u8);
+    buffer.extend_from_slice(atom.as_bytes());
+}
+
+fn write_term(buffer: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::Null => write_atom(buffer, "nil"),
+        Value::Bool(true) => write_atom(buffer, "true"),
+        Value::Bool(false) => write_atom(buffer, "false"),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                if n <= u64::from(u8::MAX) {
+                    buffer.push(SMALL_INTEGER_EXT);
+                    buffer.push(n as u8);
+                } else if n <= i32::MAX as u64 {
+                    buffer.push(INTEGER_EXT);
+                    buffer.extend_from_slice(&(n as i32).to_be_bytes());
+                } else {
+                    write_big(buffer, false, n);
+                }
+            } else if let Some(n) = n.as_i64() {
+                if n >= i64::from(i32::MIN) {
+                    buffer.push(INTEGER_EXT);
+                    buffer.extend_from_slice(&(n as i32).to_be_bytes());
+                } else {
+                    write_big(buffer, true, n.wrapping_neg() as u64);
+                }
+            } else {
+                buffer.push(NEW_FLOAT_EXT);
+                buffer.extend_from_slice(&n.as_f64().unwrap_or_default().to_bits().to_be_bytes());
+            }
+        }
+        Value::String(s) => {
+            buffer.push(BINARY_EXT);
+            buffer.extend_from_slice(&(s.len() as u32).to_be_bytes());
+            buffer.extend_from_slice(s.as_bytes());
+        }
+        Value::Array(array) => {
+            if !array.is_empty() {
+                buffer.push(LIST_EXT);
+                buffer.extend_from_slice(&(array.len() as u32).to_be_bytes());
+
+                for value in array {
+                    write_term(buffer, value);
+                }
+            }
+
+            buffer.push(NIL_EXT);
+        }
+        Value::Object(map) => {
+            buffer.push(MAP_EXT);
+            buffer.extend_from_slice(&(map.len() as u32).to_be_bytes());
+
+            for (key, value) in map {
+                write_term(buffer, &Value::String(key.clone()));
+                write_term(buffer, value);
+            }
+        }
+    }
+}
+
+fn write_big(buffer: &mut Vec<u8>, negative: bool, value: u64) {
+    let digits = value.to_le_bytes();
+    let len = digits.iter().rposition(|digit| *digit != 0).map_or(0, |i| i + 1);
+
+    buffer.push(SMALL_BIG_EXT);
+    buffer.push(len as u8);
+    buffer.push(negative as u8);
+    buffer.extend_from_slice(&digits[..len]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::ZlibEncoder, Compression};
+    use serde_json::json;
+    use std::io::Write;
+
+    fn round_trip(value: Value) -> Value {
+        decode(&encode(&value)).unwrap()
+    }
+
+    #[test]
+    fn atoms() {
+        assert_eq!(round_trip(Value::Null), Value::Null);
+        assert_eq!(round_trip(json!(true)), json!(true));
+        assert_eq!(round_trip(json!(false)), json!(false));
+        assert_eq!(round_trip(json!("nil")), json!("nil"));
+    }
+
+    #[test]
+    fn snowflakes_become_strings() {
+        let snowflake = 175928847299117063u64;
+        let encoded = encode(&json!(snowflake));
+
+        assert_eq!(encoded[1], SMALL_BIG_EXT);
+        assert_eq!(decode(&encoded).unwrap(), json!("175928847299117063"));
+        assert_eq!(round_trip(json!(MAX_SAFE_INTEGER)), json!(MAX_SAFE_INTEGER));
+    }
+
+    #[test]
+    fn negative_integers() {
+        assert_eq!(round_trip(json!(-1)), json!(-1));
+        assert_eq!(round_trip(json!(i32::MIN)), json!(i32::MIN));
+        assert_eq!(round_trip(json!(-(1i64 << 40))), json!(-(1i64 << 40)));
+    }
+
+    #[test]
+    fn nested_maps() {
+        let value = json!({
+            "op": 0,
+            "t": "MESSAGE_CREATE",
+            "d": {
+                "content": "hello",
+                "mentions": [{ "id": 300, "bot": false }],
+                "embeds": [],
+                "pinned": null,
+                "score": 1.5,
+            },
+        });
+
+        assert_eq!(round_trip(value.clone()), value);
+    }
+
+    #[test]
+    fn compressed_term() {
+        let term = &encode(&json!({ "op": 11, "d": null }))[1..];
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(term).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut data = vec![VERSION, COMPRESSED];
+        data.extend_from_slice(&(term.len() as u32).to_be_bytes());
+        data.extend_from_slice(&compressed);
+
+        assert_eq!(decode(&data).unwrap(), json!({ "op": 11, "d": null }));
+    }
+
+    #[test]
+    fn truncated_input_is_an_error() {
+        let encoded = encode(&json!({ "id": 175928847299117063u64, "name": "panda", "list": [1, 1000, -1.5] }));
+
+        for len in 0..encoded.len() {
+            assert!(
+                decode(&encoded[..len]).is_err(),
+                "decoded {} of {} bytes",
+                len,
+                encoded.len()
+            );
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::models::gateway::events::Event;
+
+use futures::{
+    channel::mpsc::{self, Receiver, SendError, Sender, UnboundedReceiver, UnboundedSender},
+    sink::SinkExt,
+    stream::Stream,
+};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Creates the channel of the events sent from the gateway process to the client. With a buffer
+/// size the channel is bounded, and the gateway process waits when it's full, without one the
+/// events are buffered until the client receives them
+pub(crate) fn event_channel(buffer: Option<usize>) -> (EventSender, EventReceiver) {
+    match buffer {
+        Some(buffer) => {
+            let (tx, rx) = mpsc::channel(buffer);
+            (EventSender::Bounded(tx), EventReceiver::Bounded(rx))
+        }
+        None => {
+            let (tx, rx) = mpsc::unbounded();
+            (EventSender::Unbounded(tx), EventReceiver::Unbounded(rx))
+        }
+    }
+}
+
+pub(crate) enum EventSender {
+    Bounded(Sender<Event>),
+    Unbounded(UnboundedSender<Event>),
+}
+
+impl EventSender {
+    /// Sends an event, it waits until there is space in bounded channels
+    pub(crate) async fn send(&mut self, event: Event) -> Result<(), SendError> {
+        match self {
+            EventSender::Bounded(tx) => tx.send(event).await,
+            EventSender::Unbounded(tx) => tx.send(event).await,
+        }
+    }
+}
+
+pub(crate) enum EventReceiver {
+    Bounded(Receiver<Event>),
+    Unbounded(UnboundedReceiver<Event>),
+}
+
+impl EventReceiver {
+    pub(crate) fn close(&mut self) {
+        match self {
+            EventReceiver::Bounded(rx) => rx.close(),
+            EventReceiver::Unbounded(rx) => rx.close(),
+        }
+    }
+}
+
+impl Stream for EventReceiver {
+    type Item = Event;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        match self.get_mut() {
+            EventReceiver::Bounded(rx) => Pin::new(rx).poll_next(cx),
+            EventReceiver::Unbounded(rx) => Pin::new(rx).poll_next(cx),
+        }
+    }
+}
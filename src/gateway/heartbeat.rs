@@ -1,23 +1,50 @@
-use crate::{models::gateway::commands::Command, runtime};
+use crate::{models::gateway::commands::Command, runtime, utils::jitter::jitter};
 
 use futures::{channel::mpsc::UnboundedSender, sink::SinkExt};
-use std::time::Duration;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 /// This function needs to be spawned to work in the background,
 /// it will send a heartbeat COMMAND to gateway every heartbeat_interval.
-/// When the channel is closed, it will be terminated
-pub(crate) async fn heartbeater(heartbeat_interval: u64, mut to_gateway: UnboundedSender<Command>) {
+/// When the channel is closed, it will be terminated.
+///
+/// `heartbeat_ack` is set when a HeartbeatACK is received, if the last heartbeat wasn't ACKed
+/// before the next one the connection is zombied, so the channel is closed to make the client
+/// reconnect and resume
+pub(crate) async fn heartbeater(
+    heartbeat_interval: u64,
+    mut to_gateway: UnboundedSender<Command>,
+    heartbeat_ack: Arc<AtomicBool>,
+    shard_id: u64,
+) {
+    // The first heartbeat is sent after `heartbeat_interval * jitter`, so many shards that
+    // reconnect at the same time don't send their heartbeats together
+    let mut delay = (heartbeat_interval as f64 * jitter(shard_id)) as u64;
+
     loop {
-        runtime::sleep(Duration::from_millis(heartbeat_interval)).await;
+        runtime::sleep(Duration::from_millis(delay)).await;
+        delay = heartbeat_interval;
 
         // Always check first if the channel it's open
         if to_gateway.is_closed() {
-            log::info!("Old heartbeater exited");
+            log::info!("[shard {}] Old heartbeater exited", shard_id);
             break;
         }
+
+        if !heartbeat_ack.swap(false, Ordering::Relaxed) {
+            log::warn!("[shard {}] HeartbeatACK not received, reconnecting...", shard_id);
+            to_gateway.close_channel();
+            break;
+        }
+
         let heartbeat = Command::new_heartbeat();
         if let Err(e) = to_gateway.send(heartbeat).await {
-            log::error!("Error when sending Heartbeat: {}", e);
+            log::error!("[shard {}] Error when sending Heartbeat: {}", shard_id, e);
         };
     }
 }
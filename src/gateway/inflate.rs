@@ -0,0 +1,107 @@
+use crate::error::{PandaError, Result};
+
+use flate2::{Decompress, FlushDecompress};
+
+// Every zlib-stream message ends with this suffix (Z_SYNC_FLUSH)
+const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
+
+/// Decompress context of a zlib-stream connection, all the messages of the connection
+/// share the same context, so it must live as long as the websocket
+pub(crate) struct Inflater {
+    decompress: Decompress,
+    buffer: Vec<u8>,
+}
+
+impl Inflater {
+    pub(crate) fn new() -> Inflater {
+        Inflater {
+            decompress: Decompress::new(true),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Adds a received frame, returns the decompressed message when the frame completes it,
+    /// a message can be sent in many frames
+    pub(crate) fn push(&mut self, frame: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.buffer.extend_from_slice(frame);
+
+        if !self.buffer.ends_with(&ZLIB_SUFFIX) {
+            return Ok(None);
+        }
+
+        let mut output = Vec::with_capacity(self.buffer.len() * 4);
+        let mut input = &self.buffer[..];
+
+        loop {
+            let total_in = self.decompress.total_in();
+            let total_out = self.decompress.total_out();
+
+            self.decompress
+                .decompress_vec(input, &mut output, FlushDecompress::Sync)
+                .map_err(|_| PandaError::WrongCompression)?;
+
+            input = &input[(self.decompress.total_in() - total_in) as usize..];
+
+            // If the output wasn't filled, all the input was decompressed
+            if input.is_empty() && output.len() < output.capacity() {
+                break;
+            }
+
+            // Nothing was consumed or produced, the data isn't a valid stream and it would loop forever
+            if self.decompress.total_in() == total_in && self.decompress.total_out() == total_out {
+                return Err(PandaError::WrongCompression);
+            }
+
+            output.reserve(output.capacity());
+        }
+
+        self.buffer.clear();
+
+        Ok(Some(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{Compress, Compression, FlushCompress};
+
+    fn compress_message(compress: &mut Compress, message: &[u8]) -> Vec<u8> {
+        let mut output = Vec::with_capacity(message.len() + 64);
+        compress
+            .compress_vec(message, &mut output, FlushCompress::Sync)
+            .unwrap();
+
+        output
+    }
+
+    #[test]
+    fn messages_split_across_frames() {
+        let mut compress = Compress::new(Compression::default(), true);
+        let mut inflater = Inflater::new();
+
+        let first = br#"{"op":10,"d":{"heartbeat_interval":41250}}"#;
+        let data = compress_message(&mut compress, first);
+        let (a, b) = data.split_at(data.len() / 2);
+
+        assert_eq!(inflater.push(a).unwrap(), None);
+        assert_eq!(inflater.push(b).unwrap().as_deref(), Some(&first[..]));
+
+        // The second message depends on the context of the first one
+        let second = br#"{"op":11,"d":null}"#;
+        let data = compress_message(&mut compress, second);
+        let (a, b) = data.split_at(3);
+
+        assert_eq!(inflater.push(a).unwrap(), None);
+        assert_eq!(inflater.push(b).unwrap().as_deref(), Some(&second[..]));
+    }
+
+    #[test]
+    fn invalid_stream_is_an_error() {
+        let mut inflater = Inflater::new();
+
+        assert!(inflater
+            .push(&[0x78, 0x9c, 0xff, 0xff, 0x00, 0x00, 0xff, 0xff])
+            .is_err());
+    }
+}
@@ -1,54 +1,101 @@
 // modules
+mod etf;
+mod event_channel;
 pub(crate) mod heartbeat;
+mod inflate;
 mod process;
+use event_channel::{event_channel, EventReceiver};
 use process::gateway_process;
 
 // crate imports
 use crate::{
+    client::Encoding,
     runtime::{self, websocket::connect_async},
     error::{PandaError, Result},
     models::gateway::{commands::Command, events::Event},
+    utils::jitter::jitter,
 };
 
 use std::{
     sync::{
-        atomic::{AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
     },
     time::Duration,
 };
 
 use futures::{
-    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    channel::mpsc::{self, UnboundedSender},
     stream::StreamExt,
 };
 
+/// Default URL of the Discord gateway
+pub(crate) const DEFAULT_GATEWAY_URL: &str = "wss://gateway.discord.gg";
+
+/// The options of the gateway connection, they are taken from the client Config
+#[derive(Clone)]
+pub(crate) struct GatewayOptions {
+    /// URL of the gateway, without the query parameters
+    pub(crate) url: String,
+    /// Compress the whole connection with zlib-stream
+    pub(crate) zlib_stream: bool,
+    pub(crate) encoding: Encoding,
+    /// Send all the payloads to the client as `Event::Raw`
+    pub(crate) raw_events: bool,
+    /// Size of the events channel, it's unbounded if it's None
+    pub(crate) event_buffer: Option<usize>,
+    /// Shard of the connection, it's added to the log messages
+    pub(crate) shard_id: u64,
+}
+
 pub(crate) struct GatewayConnection {
     last_sequence: Arc<AtomicU64>,
+    options: GatewayOptions,
     pub(crate) heartbeat_interval: u64,
-    pub(crate) from_gateway: UnboundedReceiver<Event>,
+    pub(crate) heartbeat_ack: Arc<AtomicBool>,
+    pub(crate) from_gateway: EventReceiver,
     pub(crate) to_gateway: UnboundedSender<Command>,
 }
 
 impl GatewayConnection {
-    pub(crate) async fn new() -> Result<GatewayConnection> {
+    /// Connects to the gateway, the last sequence received is stored in `last_sequence`, it's
+    /// shared with the SessionData and kept between reconnections
+    pub(crate) async fn new(last_sequence: Arc<AtomicU64>, options: GatewayOptions) -> Result<GatewayConnection> {
         // Parse discord url
-        let url = url::Url::parse("wss://gateway.discord.gg/?v=6&encoding=json").unwrap();
+        let mut url = url::Url::parse(&options.url)
+            .map_err(|_| PandaError::InvalidInput(format!("invalid gateway url: {}", options.url)))?;
+        url.query_pairs_mut()
+            .append_pair("v", "6")
+            .append_pair("encoding", options.encoding.as_str());
+
+        if options.zlib_stream {
+            url.query_pairs_mut().append_pair("compress", "zlib-stream");
+        }
 
         // Connect to the discord gateway through a websocket
-        //let (ws, _) = connect_async(url).await.map_err(|_| PandaError::CantConnectToGateway)?;
-
-        let (ws, _) = connect_async(url).await.expect("Can't connect to gateway");
+        let (ws, _) = connect_async(url).await.map_err(|_| PandaError::CantConnectToGateway)?;
 
         // Spawn gateway process manager
-        let (to_client, mut from_gateway) = mpsc::unbounded();
+        let (to_client, mut from_gateway) = event_channel(options.event_buffer);
         let (to_gateway, from_client) = mpsc::unbounded();
 
-        let last_sequence = Arc::new(AtomicU64::default());
         let last_sequence_clone = Arc::clone(&last_sequence);
 
+        // The first heartbeat doesn't need a previous ACK
+        let heartbeat_ack = Arc::new(AtomicBool::new(true));
+        let heartbeat_ack_clone = Arc::clone(&heartbeat_ack);
+        let options_clone = options.clone();
+
         runtime::spawn(async move {
-            gateway_process(ws, to_client, from_client, last_sequence_clone).await;
+            gateway_process(
+                ws,
+                to_client,
+                from_client,
+                last_sequence_clone,
+                heartbeat_ack_clone,
+                options_clone,
+            )
+            .await;
         });
 
         // Receive Hello event from the gatewat
@@ -61,7 +108,9 @@ impl GatewayConnection {
 
         Ok(GatewayConnection {
             last_sequence,
+            options,
             heartbeat_interval,
+            heartbeat_ack,
             from_gateway,
             to_gateway,
         })
@@ -73,28 +122,53 @@ impl GatewayConnection {
         Ok(())
     }
 
-    pub(crate) async fn reconnect(&mut self) -> Option<u64> {
-        // Transform last sequence to option
-        let last_sequence = match self.last_sequence.load(Ordering::Relaxed) {
+    /// Reconnects to the gateway, waiting before each attempt with an exponential backoff.
+    /// `attempts` is the number of reconnections since the last successful IDENTIFY/RESUME
+    pub(crate) async fn reconnect(&mut self, attempts: &mut u32) -> Option<u64> {
+        // Transform last sequence to option, the new connection starts without sequence
+        let last_sequence = match self.last_sequence.swap(0, Ordering::Relaxed) {
             0 => None,
             seq => Some(seq),
         };
 
+        let shard_id = self.options.shard_id;
+        log::error!(
+            "[shard {}] Disconnected from the gateway, starting reconnect...",
+            shard_id
+        );
+
         loop {
-            log::error!("Disconnected from the gateway, starting reconnect...");
-            match GatewayConnection::new().await {
+            // The first reconnection is immediate, the next ones wait more each time
+            if *attempts > 0 {
+                let delay = backoff(*attempts, shard_id);
+                log::info!(
+                    "[shard {}] Reconnecting in {:?} (attempt {})...",
+                    shard_id,
+                    delay,
+                    *attempts + 1
+                );
+                runtime::sleep(delay).await;
+            }
+            *attempts += 1;
+
+            match GatewayConnection::new(Arc::clone(&self.last_sequence), self.options.clone()).await {
                 Ok(g) => {
                     std::mem::replace(self, g);
-                    log::info!("Connected succesfully");
+                    log::info!("[shard {}] Connected succesfully (attempt {})", shard_id, *attempts);
                     break;
                 }
-                Err(_e) => {
-                    log::error!("Couldn't reconnect, trying in 3 seconds...");
-                    runtime::sleep(Duration::from_secs(3)).await;
-                }
+                Err(e) => log::error!("[shard {}] Couldn't reconnect (attempt {}): {}", shard_id, *attempts, e),
             }
         }
 
         last_sequence
     }
 }
+
+/// Returns the time to wait before the given reconnection attempt: 1s, 2s, 4s... up to 60s, with
+/// a random jitter of up to 1s, so many clients don't reconnect at the same time
+fn backoff(attempt: u32, shard_id: u64) -> Duration {
+    let secs = 1u64 << (attempt - 1).min(6);
+
+    Duration::from_secs(secs.min(60)) + Duration::from_secs_f64(jitter(shard_id))
+}
@@ -1,26 +1,28 @@
 use crate::{
+    client::Encoding,
     runtime::websocket::{WebSocket, WebSocketSender},
     error::{PandaError, Result},
-    models::gateway::{commands::Command, events::Event, payload::Payload},
+    models::gateway::{
+        commands::Command,
+        events::{CloseFrame, Event},
+        payload::Payload,
+    },
 };
 
+use super::{etf, event_channel::EventSender, inflate::Inflater, GatewayOptions};
+
 // std
 use std::{
     convert::TryFrom,
     result::Result as StdResult,
     sync::{
-        atomic::{AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
     },
 };
 
 // futures
-use futures::{
-    channel::mpsc::{UnboundedReceiver, UnboundedSender},
-    select,
-    sink::SinkExt,
-    stream::StreamExt,
-};
+use futures::{channel::mpsc::UnboundedReceiver, select, sink::SinkExt, stream::StreamExt};
 
 // tungstenite
 use async_tungstenite::tungstenite::{Error as TungsteniteError, Message as TungsteniteMessage};
@@ -31,10 +33,23 @@ type TungsteniteOptionResult = Option<StdResult<TungsteniteMessage, TungsteniteE
 #[allow(unused_must_use)]
 pub(crate) async fn gateway_process(
     ws: WebSocket,
-    mut to_client: UnboundedSender<Event>,
+    mut to_client: EventSender,
     mut from_client: UnboundedReceiver<Command>,
     last_sequence: Arc<AtomicU64>,
+    heartbeat_ack: Arc<AtomicBool>,
+    options: GatewayOptions,
 ) {
+    let GatewayOptions {
+        zlib_stream,
+        encoding,
+        raw_events,
+        shard_id,
+        ..
+    } = options;
+
+    // The decompress context of zlib-stream is shared by all the messages
+    let mut inflater = if zlib_stream { Some(Inflater::new()) } else { None };
+
     // Split the websocket
     let (mut ws_sender, ws_receiver) = ws.split();
     let mut from_gateway = ws_receiver.fuse();
@@ -43,13 +58,25 @@ pub(crate) async fn gateway_process(
             // gateway -> client
             tm = from_gateway.next()  => {
                 let last_sequence = Arc::clone(&last_sequence);
+                let mut close_frame = None;
 
-                if let Err(e) = from_gateway_process(tm, &mut to_client, last_sequence).await {
-                    log::error!("Error when receiving an event: {}", e);
-                    // Check if there are unrecoverable errors
+                if let Err(e) = from_gateway_process(tm, &mut to_client, last_sequence, &heartbeat_ack, inflater.as_mut(), encoding, raw_events, &mut close_frame).await {
+                    log::error!("[shard {}] Error when receiving an event: {}", shard_id, e);
+                    // The connection is closed with these errors, the client decides if it reconnects
                     match e {
-                        PandaError::AuthenticationFailed | PandaError::ConnectionClosed => {
-                            to_client.send(Event::Close(e)).await.expect("EVENT CLOSE");
+                        PandaError::AuthenticationFailed
+                        | PandaError::ConnectionClosed
+                        | PandaError::UnknownOpcodeSent
+                        | PandaError::InvalidDecodeSent
+                        | PandaError::InvalidSequence
+                        | PandaError::RateLimited
+                        | PandaError::SessionTimeout
+                        | PandaError::InvalidShard
+                        | PandaError::ShardingRequired
+                        | PandaError::InvalidApiGatewayVersion
+                        | PandaError::InvalidIntents
+                        | PandaError::DisallowedIntents => {
+                            to_client.send(Event::Close(e, close_frame)).await.expect("EVENT CLOSE");
                             break;
                         },
                         _ => {},
@@ -60,10 +87,10 @@ pub(crate) async fn gateway_process(
             cmd = from_client.next() => {
                 let last_sequence = Arc::clone(&last_sequence);
                 // An error means that the connection was closed
-                if let Err(e) = to_gateway_process(cmd, &mut ws_sender, last_sequence).await {
-                    log::error!("Error when sending command to gateway: {}", e);
+                if let Err(e) = to_gateway_process(cmd, &mut ws_sender, last_sequence, encoding).await {
+                    log::error!("[shard {}] Error when sending command to gateway: {}", shard_id, e);
                     // Unhandled result, TODO: Handle result
-                    to_client.send(Event::Close(PandaError::ConnectionClosed)).await;
+                    to_client.send(Event::Close(PandaError::ConnectionClosed, None)).await;
                     break;
                 }
             }
@@ -73,27 +100,73 @@ pub(crate) async fn gateway_process(
     }
 }
 
-/// This function manages all events received
+/// This function manages all events received, the close frame sent by Discord is saved in
+/// `close_frame` before it's converted into an error
+#[allow(clippy::too_many_arguments)]
 async fn from_gateway_process(
     tm: TungsteniteOptionResult,
-    to_client: &mut UnboundedSender<Event>,
+    to_client: &mut EventSender,
     last_sequence: Arc<AtomicU64>,
+    heartbeat_ack: &AtomicBool,
+    inflater: Option<&mut Inflater>,
+    encoding: Encoding,
+    raw_events: bool,
+    close_frame: &mut Option<CloseFrame>,
 ) -> Result<()> {
     // This error means connection error
     let tm = tm.ok_or_else(|| PandaError::ConnectionClosed)?;
     let msg = tm?;
 
-    // Get Payload from TungsteniteMessage
-    let p = Payload::try_from(msg)?;
+    if let TungsteniteMessage::Close(Some(frame)) = &msg {
+        *close_frame = Some(CloseFrame {
+            code: frame.code.into(),
+            reason: frame.reason.to_string(),
+        });
+    }
+
+    // With zlib-stream the binary messages are decompressed with the connection context,
+    // a message can be split in many frames, so wait until it's complete
+    let msg = match (msg, inflater) {
+        (TungsteniteMessage::Binary(v), Some(inflater)) => match (inflater.push(&v)?, encoding) {
+            (Some(data), Encoding::Etf) => TungsteniteMessage::Binary(data),
+            (Some(data), Encoding::Json) => {
+                TungsteniteMessage::Text(String::from_utf8(data).map_err(|_| PandaError::WrongCompression)?)
+            }
+            (None, _) => return Ok(()),
+        },
+        (msg, _) => msg,
+    };
+
+    // Get Payload from TungsteniteMessage, ETF messages are decoded into a JSON value
+    let p = match (msg, encoding) {
+        (TungsteniteMessage::Binary(v), Encoding::Etf) => {
+            serde_json::from_value(etf::decode(&v)?).map_err(|_| PandaError::UnknownPayloadReceived)?
+        }
+        (msg, _) => Payload::try_from(msg)?,
+    };
 
     // Get Payload sequence
     if let Some(seq) = p.s {
         last_sequence.store(seq, Ordering::Relaxed);
     }
 
+    // Send the raw payload before parsing the event, so it's received even if the parsing fails
+    if raw_events {
+        let raw = serde_json::to_value(&p)?;
+        to_client
+            .send(Event::Raw(raw))
+            .await
+            .map_err(|_| PandaError::ConnectionClosed)?;
+    }
+
     // Transform Event from Payload
     let event = Event::try_from(p)?;
 
+    // The ACK is saved here, the client could be busy running the handlers
+    if let Event::HeartbeatACK = event {
+        heartbeat_ack.store(true, Ordering::Relaxed);
+    }
+
     // Send Event to client
     to_client.send(event).await.map_err(|_| PandaError::ConnectionClosed)?;
 
@@ -105,6 +178,7 @@ async fn to_gateway_process(
     command: Option<Command>,
     to_gateway: &mut WebSocketSender,
     last_sequence: Arc<AtomicU64>,
+    encoding: Encoding,
 ) -> Result<()> {
     // Get the command
     let command = command.ok_or_else(|| PandaError::ConnectionClosed)?;
@@ -126,6 +200,15 @@ async fn to_gateway_process(
     // Transform command to TungsteniteMessage
     let tm = command.to_tungstenite_message(seq);
 
+    // Commands are serialized as JSON, so with ETF they are encoded again
+    let tm = match (tm, encoding) {
+        (TungsteniteMessage::Text(json), Encoding::Etf) => {
+            let value = serde_json::from_str(&json)?;
+            TungsteniteMessage::Binary(etf::encode(&value))
+        }
+        (tm, _) => tm,
+    };
+
     // Send command to gateway
     to_gateway.send(tm).await.map_err(|_| PandaError::ConnectionClosed)?;
 
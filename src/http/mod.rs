@@ -1,101 +1,193 @@
+mod multipart;
 mod rate_limit;
 mod routing;
 
+use multipart::Multipart;
 use rate_limit::RateLimit;
-use routing::Route;
+use routing::{RequestBody, Route};
 
 use crate::{
     error::{PandaError, Result},
     models::{
-        channel::{Channel, Embed, Message},
+        audit_log::AuditLog,
+        channel::{Attachment, Channel, Embed, Message, OverwriteType},
+        emoji::Emoji,
+        gateway::GatewayBot,
+        guild::{Ban, Guild, GuildMember, PartialGuild, Permissions, Role},
+        invite::Invite,
+        snowflake::Snowflake,
         user::User,
+        webhook::Webhook,
+    },
+    runtime,
+    utils::{
+        base64,
+        builders::{AllowedMentions, BanOptions, MessageBuilder, MessageQuery, WebhookExecuteBuilder},
+        percent_encode_component,
     },
 };
 
-use isahc::{
-    http::{Method, StatusCode},
-    prelude::*,
-    HttpClient as IsachClient,
+use isahc::{http::StatusCode, prelude::*, HttpClient as IsachClient};
+use serde::{Deserialize, Serialize};
+
+use futures::{
+    future::{self, Either},
+    io::AsyncReadExt,
+    pin_mut,
+    stream::{self, Stream},
 };
-use serde::Serialize;
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The body that Discord API sends when a request fails
+#[derive(Deserialize)]
+struct ApiErrorBody {
+    code: u64,
+    message: String,
+}
+
+/// The body of the responses of the prune routes
+#[derive(Deserialize)]
+struct PruneBody {
+    pruned: Option<u64>,
+}
+
+/// Times a request is retried after being rate limited
+const MAX_RETRIES: u8 = 3;
 
-/// It's the http client of panda, it have methods to make requests to all routes
+/// Default time to wait for a response
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default base URL of the Discord API
+pub(crate) const DEFAULT_API_URL: &str = "https://discord.com/api/v6";
+
+/// Members can't be timed out for longer than this
+const MAX_TIMEOUT: Duration = Duration::from_secs(28 * 24 * 60 * 60);
+
+/// Messages older than this can't be bulk deleted
+const BULK_DELETE_MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// It's the http client of panda, it have methods to make requests to all routes. It's cheap
+/// to clone, and the clones share the connections and the rate limits, so one client can be
+/// used by many shards.
+#[derive(Clone)]
 pub struct HttpClient {
     token: String,
-    client: IsachClient,
+    api_url: String,
+    client: Arc<IsachClient>,
     rate_limit: RateLimit,
 }
 
 impl HttpClient {
-    /// Creates a new http client
+    /// Creates a new http client, the requests fail with [`PandaError::Timeout`] if Discord
+    /// doesn't respond in 30 seconds
+    ///
+    /// [`PandaError::Timeout`]: ../../panda/enum.PandaError.html#variant.Timeout
     pub fn new(token: impl Into<String>) -> HttpClient {
-        let client = IsachClient::new().expect("Can't create Http Client");
+        HttpClient::new_with_timeout(token, DEFAULT_TIMEOUT)
+    }
+
+    /// Creates a new http client with the given timeout for each request
+    pub fn new_with_timeout(token: impl Into<String>, timeout: Duration) -> HttpClient {
+        let client = IsachClient::builder()
+            .timeout(timeout)
+            .build()
+            .expect("Can't create Http Client");
+
         HttpClient {
             token: token.into(),
-            client,
+            api_url: DEFAULT_API_URL.into(),
+            client: Arc::new(client),
             rate_limit: RateLimit::default(),
         }
     }
 
-    async fn _make_request<B: Into<Body>>(&self, route: Route<B>) -> Result<Response<Body>> {
-        // Check and wait if we reach the limit
-        self.rate_limit.check_and_sleep(&route.bucket_key).await;
+    /// Set the base URL of the requests, by default it's `https://discord.com/api/v6`. It can be
+    /// used to send the requests to a proxy or to a mock server in tests.
+    pub fn set_api_url(&mut self, url: impl Into<String>) {
+        self.api_url = url.into().trim_end_matches('/').into();
+    }
 
-        // TODO: Improve this
-        let response = match route.method {
-            Method::GET | Method::PUT | Method::DELETE => {
-                let request = Request::builder()
-                    .method(route.method)
-                    .uri(&route.uri)
-                    .header("Authorization", &self.token)
-                    .body(())
-                    .unwrap();
+    async fn _make_request<B: RequestBody>(&self, route: Route<B>) -> Result<Response<Body>> {
+        self._make_request_with_reason(route, None).await
+    }
 
-                // Get response
-                self.client.send_async(request).await?
-            }
-            Method::POST | Method::PATCH => {
-                let request = Request::builder()
-                    .method(route.method)
-                    .uri(&route.uri)
-                    .header("Authorization", &self.token)
-                    .header("Content-Type", "application/json")
-                    .body(route.body)
-                    .unwrap();
-
-                // Get response
-                self.client.send_async(request).await?
+    /// Same as `_make_request` but it adds the X-Audit-Log-Reason header if there is a reason
+    async fn _make_request_with_reason<B: RequestBody>(
+        &self,
+        route: Route<B>,
+        reason: Option<&str>,
+    ) -> Result<Response<Body>> {
+        let mut retries = 0;
+
+        loop {
+            // Check and wait if we reach the limit
+            self.rate_limit.check_and_sleep(&route.bucket_key).await;
+
+            let mut request = Request::builder()
+                .method(route.method.clone())
+                .uri(format!("{}{}", self.api_url, route.uri))
+                .header("Authorization", &self.token);
+
+            // Only requests with a body have a content type
+            if let Some(content_type) = route.body.content_type() {
+                request = request.header("Content-Type", content_type);
             }
-            _ => unimplemented!(),
-        };
 
-        // Update the limit with the response headers
-        self.rate_limit.update(route.bucket_key, &response).await;
+            let body: Body = route.body.clone().into();
+
+            // The reason can have any character, so it needs to be encoded
+            if let Some(reason) = reason {
+                request = request.header("X-Audit-Log-Reason", percent_encode_component(reason));
+            }
 
-        Ok(response)
+            // Get response
+            let mut response = self.client.send_async(request.body(body).unwrap()).await?;
+
+            // Update the limit with the response headers, on a 429 wait and retry
+            match self.rate_limit.update(route.bucket_key.clone(), &response).await {
+                Some(retry_after) if retries < MAX_RETRIES => {
+                    log::warn!("Rate limited on {}, retrying in {:?}", route.bucket_key, retry_after);
+                    runtime::sleep(retry_after).await;
+                    retries += 1;
+                }
+                _ => {
+                    self._catch_http_errors(&mut response)?;
+
+                    return Ok(response);
+                }
+            }
+        }
     }
 
-    // TODO: Rename this and improve
-    fn _catch_http_errors(&self, res: &Response<Body>) -> Result<()> {
-        let err = match res.status() {
-            StatusCode::OK
-            | StatusCode::CREATED
-            | StatusCode::NO_CONTENT
-            | StatusCode::NOT_MODIFIED
-            | StatusCode::TOO_MANY_REQUESTS => return Ok(()),
+    /// Returns the error of the response if it isn't successful. Discord sends a JSON body with the
+    /// error code and message, if it couldn't be deserialized the error is guessed from the status code
+    fn _catch_http_errors(&self, res: &mut Response<Body>) -> Result<()> {
+        let status = res.status();
+
+        if status.is_success() || status == StatusCode::NOT_MODIFIED {
+            return Ok(());
+        }
+
+        if let Ok(body) = res.json::<ApiErrorBody>() {
+            return Err(PandaError::Api {
+                code: body.code,
+                message: body.message,
+            });
+        }
 
+        let err = match status {
             StatusCode::BAD_REQUEST => PandaError::HttpImproperlyFormatted,
-            StatusCode::FORBIDDEN => PandaError::HttpForbidden, // no autorizado
+            StatusCode::UNAUTHORIZED => PandaError::HttpUnauthorized,
+            StatusCode::FORBIDDEN => PandaError::HttpForbidden,
             StatusCode::NOT_FOUND => PandaError::HttpInvalidParameters, // not found or bad format
-            StatusCode::METHOD_NOT_ALLOWED => PandaError::HttpNoResponse, // method not allowed
-            // HANDLED BY RATELIMIT StatusCode::TOO_MANY_REQUESTS => PandaError::HttpNoResponse,
-            StatusCode::BAD_GATEWAY => PandaError::HttpNoResponse, // gateway unavailable
             _ => PandaError::HttpNoResponse,
         };
 
         Err(err)
-
-        //TODO: Remove HttpNoResponse here
     }
 
     // *******************************************************************************
@@ -110,15 +202,15 @@ impl HttpClient {
         let route = Route::get_channel(channel_id);
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        res.json().map_err(PandaError::Deserialize)
     }
 
     /// Update a channel's settings. Requires the **MANAGE_CHANNELS** permission for the guild.
-    /// Returns a [`Channel`] on success. It's recommended to use [`MessageEdit`] builder.
+    /// Returns a [`Channel`] on success. It's recommended to use [`ChannelEdit`] builder.
     /// Fires a [`ChannelUpdate`] event.
     ///
     /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
-    /// [`MessageEdit`]: ../../panda/utils/builder/struct.MessageEdit.html
+    /// [`ChannelEdit`]: ../../panda/utils/builders/struct.ChannelEdit.html
     /// [`ChannelUpdate`]: ../../panda/models/gateway/events/struct.ChannelUpdate.html
     pub async fn edit_channel(&self, channel_id: impl AsRef<str>, body: impl Serialize) -> Result<Channel> {
         // Create route
@@ -127,8 +219,7 @@ impl HttpClient {
 
         let mut res = self._make_request(route).await?;
 
-        // If an error wasn't returned, it's safe to unwrap
-        Ok(res.json()?)
+        res.json().map_err(PandaError::Deserialize)
     }
 
     /// Delete a channel, or close a private message. Requires the **MANAGE_CHANNELS** permission
@@ -143,11 +234,99 @@ impl HttpClient {
 
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns a Vec<[`Message`]> with the last messages of a channel. If operating on a guild
+    /// channel, this endpoint requires the **VIEW_CHANNEL** permission to be present on the current user.
+    /// The `limit` must be between 1 and 100.
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    pub async fn get_messages(&self, channel_id: impl AsRef<str>, limit: u8) -> Result<Vec<Message>> {
+        let query = MessageQuery::new().limit(limit);
+
+        self.get_channel_messages(channel_id, query).await
+    }
+
+    /// Returns a Vec<[`Message`]> of a channel that match the given [`MessageQuery`]. If
+    /// operating on a guild channel, this endpoint requires the **VIEW_CHANNEL** permission to
+    /// be present on the current user. It fails if more than one of `before`, `after` and
+    /// `around` is set.
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`MessageQuery`]: ../../panda/utils/builders/struct.MessageQuery.html
+    pub async fn get_channel_messages(&self, channel_id: impl AsRef<str>, query: MessageQuery) -> Result<Vec<Message>> {
+        query.check()?;
+
+        // Create route
+        let route = Route::get_channel_messages(channel_id, &query);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns a Stream of all the [`Message`]s of a channel, from the newest to the oldest. The
+    /// messages are requested in pages of 100 using [`get_messages_before`], the stream ends
+    /// when there are no more messages or after the first error.
+    ///
+    /// ```no_run
+    /// # async fn f(http: panda::HttpClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::{pin_mut, StreamExt};
+    ///
+    /// let messages = http.messages_iter("channel id");
+    /// pin_mut!(messages);
+    ///
+    /// while let Some(msg) = messages.next().await {
+    ///     println!("{}", msg?.content);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`get_messages_before`]: struct.HttpClient.html#method.get_messages_before
+    pub fn messages_iter<'a>(&'a self, channel_id: impl AsRef<str>) -> impl Stream<Item = Result<Message>> + 'a {
+        let channel_id = channel_id.as_ref().to_string();
+
+        // The state is the current page, the id of the oldest message received and if there are more pages
+        let state = (Vec::new().into_iter(), None, true);
+
+        stream::unfold(state, move |(mut page, before, has_more): (_, Option<String>, _)| {
+            let channel_id = channel_id.clone();
+
+            async move {
+                if let Some(msg) = page.next() {
+                    return Some((Ok(msg), (page, before, has_more)));
+                }
+
+                if !has_more {
+                    return None;
+                }
+
+                let messages = match &before {
+                    Some(before) => self.get_messages_before(&channel_id, before, 100).await,
+                    None => self.get_messages(&channel_id, 100).await,
+                };
+
+                match messages {
+                    Ok(messages) => {
+                        // A page with less than 100 messages is the last one
+                        let has_more = messages.len() == 100;
+                        let before = messages.last().map(|msg| msg.id.to_string());
+
+                        let mut page = messages.into_iter();
+                        page.next().map(|msg| (Ok(msg), (page, before, has_more)))
+                    }
+                    Err(e) => Some((Err(e), (page, before, false))),
+                }
+            }
+        })
     }
 
     /// Returns a Vec<[`Message`]> of a channel. If operating on a guild channel, this endpoint
     /// requires the **VIEW_CHANNEL** permission to be present on the current user.
+    /// The `limit` must be between 1 and 100.
     ///
     /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
     pub async fn get_messages_around(
@@ -156,16 +335,14 @@ impl HttpClient {
         message_id: impl AsRef<str>,
         limit: u8,
     ) -> Result<Vec<Message>> {
-        // Create route
-        let route = Route::get_channel_messages("around", channel_id, message_id, limit);
-
-        let mut res = self._make_request(route).await?;
+        let query = MessageQuery::new().around(message_id.as_ref()).limit(limit);
 
-        Ok(res.json()?)
+        self.get_channel_messages(channel_id, query).await
     }
 
     /// Returns a Vec<[`Message`]> of a channel. If operating on a guild channel, this endpoint
     /// requires the **VIEW_CHANNEL** permission to be present on the current user.
+    /// The `limit` must be between 1 and 100.
     ///
     /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
     pub async fn get_messages_before(
@@ -174,16 +351,14 @@ impl HttpClient {
         message_id: impl AsRef<str>,
         limit: u8,
     ) -> Result<Vec<Message>> {
-        // Create route
-        let route = Route::get_channel_messages("before", channel_id, message_id, limit);
+        let query = MessageQuery::new().before(message_id.as_ref()).limit(limit);
 
-        let mut res = self._make_request(route).await?;
-
-        Ok(res.json()?)
+        self.get_channel_messages(channel_id, query).await
     }
 
     /// Returns a Vec<[`Message`]> of a channel. If operating on a guild channel, this endpoint
     /// requires the **VIEW_CHANNEL** permission to be present on the current user.
+    /// The `limit` must be between 1 and 100.
     ///
     /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
     pub async fn get_messages_after(
@@ -192,12 +367,9 @@ impl HttpClient {
         message_id: impl AsRef<str>,
         limit: u8,
     ) -> Result<Vec<Message>> {
-        // Create route
-        let route = Route::get_channel_messages("after", channel_id, message_id, limit);
-
-        let mut res = self._make_request(route).await?;
+        let query = MessageQuery::new().after(message_id.as_ref()).limit(limit);
 
-        Ok(res.json()?)
+        self.get_channel_messages(channel_id, query).await
     }
 
     /// Returns a specific [`Message`] in the channel. If operating on a guild channel, this endpoint
@@ -210,7 +382,7 @@ impl HttpClient {
 
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        res.json().map_err(PandaError::Deserialize)
     }
 
     /// Creates a new message, and returns the [`Message`]. This will also trigger
@@ -231,7 +403,81 @@ impl HttpClient {
         let route = Route::create_message(channel_id, body);
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Creates a new message from a [`MessageBuilder`], and returns the [`Message`].
+    /// This will also trigger [`MessageCreate`] event
+    ///
+    /// [`MessageBuilder`]: ../../panda/utils/builders/struct.MessageBuilder.html
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`MessageCreate`]: ../../panda/models/gateway/events/struct.MessageCreate.html
+    pub async fn send_message_with(&self, channel_id: impl AsRef<str>, message: MessageBuilder) -> Result<Message> {
+        let body = serde_json::to_string(&message)?;
+
+        // Create route
+        let route = Route::create_message(channel_id, body);
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Replies to a message with the given content, and returns the [`Message`]. The reply
+    /// doesn't ping anyone, use [`send_message_with`] and [`MessageBuilder.reply_to`] to set
+    /// the allowed mentions. If `fail_if_not_exists` is false and the message was deleted, it's
+    /// sent as a normal message. This will also trigger [`MessageCreate`] event
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`send_message_with`]: struct.HttpClient.html#method.send_message_with
+    /// [`MessageBuilder.reply_to`]: ../../panda/utils/builders/struct.MessageBuilder.html#method.reply_to
+    /// [`MessageCreate`]: ../../panda/models/gateway/events/struct.MessageCreate.html
+    pub async fn reply_to(
+        &self,
+        channel_id: impl AsRef<str>,
+        message_id: impl Into<String>,
+        content: impl Into<String>,
+        fail_if_not_exists: bool,
+    ) -> Result<Message> {
+        let message = MessageBuilder::new()
+            .content(content)
+            .reply_to(message_id, fail_if_not_exists)
+            .allowed_mentions(AllowedMentions::none());
+
+        self.send_message_with(channel_id, message).await
+    }
+
+    /// Creates a new message with the given files attached, each file is a tuple of the file name
+    /// and its content, and returns the [`Message`]. This will also trigger [`MessageCreate`] event
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`MessageCreate`]: ../../panda/models/gateway/events/struct.MessageCreate.html
+    pub async fn send_files(
+        &self,
+        channel_id: impl AsRef<str>,
+        content: Option<&str>,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> Result<Message> {
+        if files.is_empty() {
+            return Err(PandaError::InvalidInput("at least one file must be sent".into()));
+        }
+
+        let payload = serde_json::json!({
+            "content": content.unwrap_or_default(),
+            "tts": false
+        });
+
+        let mut body = Multipart::new();
+        body.add_text("payload_json", &serde_json::to_string(&payload)?);
+
+        for (i, (filename, data)) in files.iter().enumerate() {
+            body.add_file(&format!("file{}", i), filename, data);
+        }
+
+        // Create route
+        let route = Route::create_message(channel_id, body.finish());
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
     }
 
     /// Creates a new message, and returns the [`Message`]. This will also trigger
@@ -252,7 +498,7 @@ impl HttpClient {
 
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        res.json().map_err(PandaError::Deserialize)
     }
 
     /// Add a reaction to a [`Message`], it needs the [`Channel`] ID, and [`Message`] ID
@@ -312,8 +558,10 @@ impl HttpClient {
         Ok(())
     }
 
-    /// Get all [`User`]s that reacted with given emoji to a [`Message`],
-    /// it needs the [`Channel`] ID, [`Message`] ID
+    /// Get the [`User`]s that reacted with given emoji to a [`Message`],
+    /// it needs the [`Channel`] ID, [`Message`] ID. The users are sorted by ID, `after` is the
+    /// highest user ID in the previous page, use `None` to get the first page. `limit` is the max
+    /// number of users to return (1-100, 25 by default).
     ///
     /// [`Channel`]: ../../panda/models/channel/struct.channel.html
     /// [`Message`]: ../../panda/models/channel/struct.Message.html
@@ -323,12 +571,66 @@ impl HttpClient {
         channel_id: impl AsRef<str>,
         message_id: impl AsRef<str>,
         emoji: impl AsRef<str>,
+        after: Option<&str>,
+        limit: Option<u8>,
     ) -> Result<Vec<User>> {
-        let route = Route::get_reactions(channel_id, message_id, emoji);
+        if let Some(limit) = limit {
+            if limit == 0 || limit > 100 {
+                return Err(PandaError::InvalidInput(format!(
+                    "the reactions limit must be between 1 and 100, but it was {}",
+                    limit
+                )));
+            }
+        }
+
+        let route = Route::get_reactions(channel_id, message_id, emoji, after, limit);
 
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns true if the user reacted with the given emoji to a [`Message`]. The unicode
+    /// emojis are passed as they are, and the custom emojis as `name:id`. The users are
+    /// requested in pages of 100, and it stops when the user is found or when the page passed
+    /// its ID, as they are sorted by ID.
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    pub async fn has_reacted(
+        &self,
+        channel_id: impl AsRef<str>,
+        message_id: impl AsRef<str>,
+        emoji: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+    ) -> Result<bool> {
+        let user_id: u64 = user_id
+            .as_ref()
+            .parse()
+            .map_err(|_| PandaError::InvalidInput(format!("invalid user id {}", user_id.as_ref())))?;
+        let mut after: Option<String> = None;
+
+        loop {
+            let users = self
+                .get_reactions(
+                    channel_id.as_ref(),
+                    message_id.as_ref(),
+                    emoji.as_ref(),
+                    after.as_deref(),
+                    Some(100),
+                )
+                .await?;
+
+            if users.iter().any(|u| u.id.as_u64() == user_id) {
+                return Ok(true);
+            }
+
+            match users.last() {
+                Some(last) if users.len() == 100 && last.id.as_u64() < user_id => {
+                    after = Some(last.id.to_string());
+                }
+                _ => return Ok(false),
+            }
+        }
     }
 
     /// Deletes all reactions on a [`Message`]. This endpoint requires the **MANAGE_MESSAGES**
@@ -362,9 +664,11 @@ impl HttpClient {
         Ok(())
     }
 
-    /// Edits message, and returns the [`Message`]. This will also trigger [`MessageUpdate`] event
+    /// Edits message, and returns the [`Message`]. It's recommended to use [`MessageEdit`] builder.
+    /// This will also trigger [`MessageUpdate`] event
     ///
     /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`MessageEdit`]: ../../panda/utils/builders/struct.MessageEdit.html
     /// [`MessageUpdate`]: ../../panda/models/gateway/events/struct.MessageUpdate.html
     pub async fn edit_message(
         &self,
@@ -378,7 +682,7 @@ impl HttpClient {
 
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        res.json().map_err(PandaError::Deserialize)
     }
 
     /// Delete a [`Message`], This will also trigger [`MessageDelete`] event
@@ -393,13 +697,45 @@ impl HttpClient {
         Ok(())
     }
 
-    /// Delete a a bulk of [`Message`] (2 - 100), This will also trigger [`MessageDeleteBulk`] event.
+    /// Crosspost a message of an announcement channel to the channels following it, and returns
+    /// the [`Message`]. Discord returns an error if the channel isn't an announcement channel.
+    /// Requires the **SEND_MESSAGES** permission for own messages, or **MANAGE_MESSAGES** for
+    /// the other ones.
     ///
     /// [`Message`]: ../../panda/models/channel/struct.Message.html
-    /// [`MessageDelete`]: ../../panda/models/gateway/events/struct.MessageDelete.html
+    pub async fn crosspost_message(&self, channel_id: impl AsRef<str>, message_id: impl AsRef<str>) -> Result<Message> {
+        let route = Route::crosspost_message(channel_id, message_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Delete a bulk of [`Message`] (2 - 100), This will also trigger [`MessageDeleteBulk`] event.
+    /// Discord rejects the whole bulk if a message is older than 14 days, so it returns
+    /// `PandaError::InvalidInput` if there is an old message, or if the number of messages is
+    /// out of the range. Use [`delete_messages`] to delete any number of messages.
+    ///
+    /// [`Message`]: ../../panda/models/channel/struct.Message.html
+    /// [`MessageDeleteBulk`]: ../../panda/models/gateway/events/struct.MessageDeleteBulk.html
+    /// [`delete_messages`]: struct.HttpClient.html#method.delete_messages
     pub async fn delete_many_messages(&self, channel_id: impl AsRef<str>, messages: &[&str]) -> Result<()> {
+        if messages.len() < 2 || messages.len() > 100 {
+            return Err(PandaError::InvalidInput(format!(
+                "the number of messages to bulk delete must be between 2 and 100, but it was {}",
+                messages.len()
+            )));
+        }
+
+        if let Some(id) = messages.iter().find(|id| !is_bulk_deletable(id)) {
+            return Err(PandaError::InvalidInput(format!(
+                "the message {} is older than 14 days, it can't be bulk deleted",
+                id
+            )));
+        }
+
         let body = serde_json::json!({ "messages": messages });
-        let body = serde_json::to_string(&body).unwrap();
+        let body = serde_json::to_string(&body)?;
 
         let route = Route::bulk_delete_messages(channel_id, body);
 
@@ -408,25 +744,107 @@ impl HttpClient {
         Ok(())
     }
 
-    /// Edit the channel permission overwrites for a user or role in a channel. Only usable
+    /// Delete any number of messages, the messages are deleted in bulks of 100 with
+    /// [`delete_many_messages`], and the ones older than 14 days, or a last single message,
+    /// are deleted one by one with [`delete_message`]. Requires the **MANAGE_MESSAGES** permission.
+    ///
+    /// [`delete_many_messages`]: struct.HttpClient.html#method.delete_many_messages
+    /// [`delete_message`]: struct.HttpClient.html#method.delete_message
+    pub async fn delete_messages(&self, channel_id: impl AsRef<str>, messages: &[&str]) -> Result<()> {
+        let channel_id = channel_id.as_ref();
+        let (recent, old): (Vec<&str>, Vec<&str>) = messages.iter().partition(|id| is_bulk_deletable(id));
+
+        for chunk in recent.chunks(100) {
+            match chunk {
+                [id] => self.delete_message(channel_id, id).await?,
+                _ => self.delete_many_messages(channel_id, chunk).await?,
+            }
+        }
+
+        for id in old {
+            self.delete_message(channel_id, id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Edit the channel permission overwrites for a user or role in a channel, `overwrite_id` is
+    /// the ID of the role or the user.
+    /// Only usable for guild channels. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`ChannelUpdate`] event.
+    ///
+    /// [`ChannelUpdate`]: ../../panda/models/gateway/events/struct.ChannelUpdate.html
+    pub async fn edit_channel_permissions(
+        &self,
+        channel_id: impl AsRef<str>,
+        overwrite_id: impl AsRef<str>,
+        allow: Permissions,
+        deny: Permissions,
+        kind: OverwriteType,
+    ) -> Result<()> {
+        let body = serde_json::json!({
+            "allow": allow,
+            "deny": deny,
+            "type": kind
+        });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::edit_channel_permissions(channel_id, overwrite_id, body);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Delete a channel permission overwrite for a user or role in a channel. Only usable
     /// for guild channels. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`ChannelUpdate`] event.
     ///
-    /// [`Message`]: ../../panda/models/channel/struct.Message.html
-    /// [`MessageDelete`]: ../../panda/models/gateway/events/struct.MessageDelete.html
-    pub async fn edit_channel_permissions(&self, _channel_id: impl AsRef<str>) -> Result<()> {
-        unimplemented!();
-        // // Create RateLimit Key
-        // let route = Route::edit_channel_permissions(channel_id, overwrite_id, body);
+    /// [`ChannelUpdate`]: ../../panda/models/gateway/events/struct.ChannelUpdate.html
+    pub async fn delete_channel_permission(
+        &self,
+        channel_id: impl AsRef<str>,
+        overwrite_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let route = Route::delete_channel_permission(channel_id, overwrite_id);
 
-        // let _res = self._make_request(route).await?;
+        let _res = self._make_request(route).await?;
 
-        // Ok(())
+        Ok(())
     }
 
     // // pub async fn get_channel_invites() {}
-    // // pub async fn create_channel_invite() {}
 
-    // // pub async fn delete_channel_permissions() {}
+    /// Create a new invite for the channel, and returns the [`Invite`]. `max_age` is the duration
+    /// in seconds (0 for never), `max_uses` the max number of uses (0 for unlimited), `temporary`
+    /// makes the members that joined with it to be kicked when they disconnect, unless they got a
+    /// role, and `unique` avoids reusing a similar invite. Requires the **CREATE_INSTANT_INVITE**
+    /// permission. Fires an [`InviteCreate`] event.
+    ///
+    /// [`Invite`]: ../../panda/models/invite/struct.Invite.html
+    /// [`InviteCreate`]: ../../panda/models/gateway/events/struct.InviteCreate.html
+    pub async fn create_channel_invite(
+        &self,
+        channel_id: impl AsRef<str>,
+        max_age: u64,
+        max_uses: u64,
+        temporary: bool,
+        unique: bool,
+    ) -> Result<Invite> {
+        let body = serde_json::json!({
+            "max_age": max_age,
+            "max_uses": max_uses,
+            "temporary": temporary,
+            "unique": unique
+        });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::create_channel_invite(channel_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
 
     /// Post a typing indicator for the specified channel.
     /// Fires a [`TypingStart`] Gateway event
@@ -439,26 +857,58 @@ impl HttpClient {
         Ok(())
     }
 
+    /// Post a typing indicator for the specified channel while the given future is running,
+    /// the indicator is posted again every 8 seconds because it only lasts 10 seconds.
+    /// Returns the output of the future. Only the error of the first indicator is returned,
+    /// the next ones are logged, so they don't cancel the future.
+    ///
+    /// ```no_run
+    /// # async fn f(http: &panda::HttpClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let answer = http.trigger_typing_while("channel id", async { "some slow work" }).await?;
+    /// http.send_message("channel id", answer).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn trigger_typing_while<F: Future>(&self, channel_id: impl AsRef<str>, future: F) -> Result<F::Output> {
+        let channel_id = channel_id.as_ref();
+        self.trigger_typing(channel_id).await?;
+
+        // The indicator is posted concurrently, so the future is polled while it's sent
+        let typing = async {
+            loop {
+                runtime::sleep(Duration::from_secs(8)).await;
+                if let Err(e) = self.trigger_typing(channel_id).await {
+                    log::warn!("Error triggering typing in channel {}: {}", channel_id, e);
+                }
+            }
+        };
+
+        pin_mut!(future);
+        pin_mut!(typing);
+
+        match future::select(future, typing).await {
+            Either::Left((output, _)) => Ok(output),
+            Either::Right(_) => unreachable!("the typing loop never ends"),
+        }
+    }
+
     /// Returns all pinned messages in the channel as a Vec of [`Message`] objects.
     ///
     /// [`Message`]: ../../panda/models/channel/struct.Message.html
     pub async fn get_pinned_messages(&self, channel_id: impl AsRef<str>) -> Result<Vec<Message>> {
-
-        // TODO: Returns a 204 empty response on success.
-
         let route = Route::get_pinned_messages(channel_id);
 
         let mut res = self._make_request(route).await?;
 
-        Ok(res.json()?)
+        res.json().map_err(PandaError::Deserialize)
     }
 
     /// Pin a message in a channel. Requires the **MANAGE_MESSAGES** permission
-    /// **The max pinned messages is 50.**
+    /// **The max pinned messages is 50.** Fires a [`ChannelPinsUpdate`] event.
+    ///
+    /// [`ChannelPinsUpdate`]: ../../panda/models/gateway/events/struct.ChannelPinsUpdate.html
     pub async fn pin_message(&self, channel_id: impl AsRef<str>, message_id: impl AsRef<str>) -> Result<()> {
-
-        // TODO: Returns a 204 empty response on success.
-
+        // Returns a 204 empty response on success
         let route = Route::add_pinned_channel_message(channel_id, message_id);
 
         let _ = self._make_request(route).await?;
@@ -466,11 +916,12 @@ impl HttpClient {
         Ok(())
     }
 
-    /// Pin a message in a channel. Requires the **MANAGE_MESSAGES** permission.
+    /// Unpin a message in a channel. Requires the **MANAGE_MESSAGES** permission.
+    /// Fires a [`ChannelPinsUpdate`] event.
+    ///
+    /// [`ChannelPinsUpdate`]: ../../panda/models/gateway/events/struct.ChannelPinsUpdate.html
     pub async fn unpin_message(&self, channel_id: impl AsRef<str>, message_id: impl AsRef<str>) -> Result<()> {
-
-        // TODO: Returns a 204 empty response on success.
-
+        // Returns a 204 empty response on success
         let route = Route::delete_pinned_channel_message(channel_id, message_id);
 
         let _ = self._make_request(route).await?;
@@ -481,4 +932,734 @@ impl HttpClient {
     // PUT/channels/{channel.id}/recipients/{user.id}
 
     // DELETE/channels/{channel.id}/recipients/{user.id}
+
+    // *******************************************************************************
+    // * GUILD METHODS
+    // *******************************************************************************
+
+    /// Returns the [`Guild`] for the given ID
+    ///
+    /// [`Guild`]: ../../panda/models/guild/struct.Guild.html
+    pub async fn get_guild(&self, guild_id: impl AsRef<str>) -> Result<Guild> {
+        let route = Route::get_guild(guild_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns a Vec<[`Channel`]> of the guild channels
+    ///
+    /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
+    pub async fn get_guild_channels(&self, guild_id: impl AsRef<str>) -> Result<Vec<Channel>> {
+        let route = Route::get_guild_channels(guild_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns the [`Channel`] of the guild with the given name, the name must match exactly.
+    /// If many channels have the same name, the first one by position is returned.
+    ///
+    /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
+    pub async fn find_channel_by_name(&self, guild_id: impl AsRef<str>, name: &str) -> Result<Option<Channel>> {
+        let channels = self.get_guild_channels(guild_id).await?;
+
+        let channel = channels
+            .into_iter()
+            .filter(|c| c.name.as_deref() == Some(name))
+            .min_by(|a, b| a.position.cmp(&b.position).then_with(|| a.id.cmp(&b.id)));
+
+        Ok(channel)
+    }
+
+    /// Create a new channel in a guild. Requires the **MANAGE_CHANNELS** permission.
+    /// Returns the new [`Channel`] on success. It's recommended to use [`ChannelBuilder`] builder.
+    /// Fires a [`ChannelCreate`] event.
+    ///
+    /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
+    /// [`ChannelBuilder`]: ../../panda/utils/builders/struct.ChannelBuilder.html
+    /// [`ChannelCreate`]: ../../panda/models/gateway/events/struct.ChannelCreate.html
+    pub async fn create_channel(&self, guild_id: impl AsRef<str>, body: impl Serialize) -> Result<Channel> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::create_guild_channel(guild_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Create a new role in a guild. Requires the **MANAGE_ROLES** permission.
+    /// Returns the new [`Role`] on success. It's recommended to use [`RoleBuilder`] builder.
+    /// Fires a [`GuildRoleCreate`] event.
+    ///
+    /// [`Role`]: ../../panda/models/guild/struct.Role.html
+    /// [`RoleBuilder`]: ../../panda/utils/builders/struct.RoleBuilder.html
+    /// [`GuildRoleCreate`]: ../../panda/models/gateway/events/struct.GuildRoleCreate.html
+    pub async fn create_role(&self, guild_id: impl AsRef<str>, body: impl Serialize) -> Result<Role> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::create_guild_role(guild_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns a Vec<[`Role`]> of the guild roles
+    ///
+    /// [`Role`]: ../../panda/models/guild/struct.Role.html
+    pub async fn get_guild_roles(&self, guild_id: impl AsRef<str>) -> Result<Vec<Role>> {
+        let route = Route::get_guild_roles(guild_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Edit a role of a guild. Requires the **MANAGE_ROLES** permission.
+    /// Returns the updated [`Role`] on success. It's recommended to use [`RoleBuilder`] builder,
+    /// only the fields that were set are changed. Fires a [`GuildRoleUpdate`] event.
+    ///
+    /// [`Role`]: ../../panda/models/guild/struct.Role.html
+    /// [`RoleBuilder`]: ../../panda/utils/builders/struct.RoleBuilder.html
+    /// [`GuildRoleUpdate`]: ../../panda/models/gateway/events/struct.GuildRoleUpdate.html
+    pub async fn edit_role(
+        &self,
+        guild_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+        body: impl Serialize,
+    ) -> Result<Role> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_guild_role(guild_id, role_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Delete a role of a guild. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`GuildRoleDelete`] event.
+    ///
+    /// [`GuildRoleDelete`]: ../../panda/models/gateway/events/struct.GuildRoleDelete.html
+    pub async fn delete_role(&self, guild_id: impl AsRef<str>, role_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::delete_guild_role(guild_id, role_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Returns the [`AuditLog`] of the guild, it can be filtered by the user that made the
+    /// changes and by the action type, `limit` is the max number of entries (1-100, 50 by default).
+    /// Requires the **VIEW_AUDIT_LOG** permission.
+    ///
+    /// [`AuditLog`]: ../../panda/models/audit_log/struct.AuditLog.html
+    pub async fn get_audit_log(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: Option<&str>,
+        action_type: Option<u16>,
+        limit: Option<u8>,
+    ) -> Result<AuditLog> {
+        let route = Route::get_guild_audit_log(guild_id, user_id, action_type, limit);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns a Vec<[`GuildMember`]> of the guild members. `limit` is the max number of members
+    /// to return (1-1000), and `after` is the highest user ID in the previous page, use `None`
+    /// to get the first page.
+    ///
+    /// [`GuildMember`]: ../../panda/models/guild/struct.GuildMember.html
+    pub async fn get_guild_members(
+        &self,
+        guild_id: impl AsRef<str>,
+        limit: u16,
+        after: Option<&str>,
+    ) -> Result<Vec<GuildMember>> {
+        let route = Route::list_guild_members(guild_id, limit, after);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns a Vec<[`GuildMember`]> of the guild members whose username or nickname starts
+    /// with `query`. `limit` is the max number of members to return (1-1000).
+    ///
+    /// [`GuildMember`]: ../../panda/models/guild/struct.GuildMember.html
+    pub async fn search_guild_members(
+        &self,
+        guild_id: impl AsRef<str>,
+        query: &str,
+        limit: u16,
+    ) -> Result<Vec<GuildMember>> {
+        if limit == 0 || limit > 1000 {
+            return Err(PandaError::InvalidInput(format!(
+                "the members limit must be between 1 and 1000, but it was {}",
+                limit
+            )));
+        }
+
+        let route = Route::search_guild_members(guild_id, query, limit);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns the [`GuildMember`] of the user in the guild
+    ///
+    /// [`GuildMember`]: ../../panda/models/guild/struct.GuildMember.html
+    pub async fn get_guild_member(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Result<GuildMember> {
+        let route = Route::get_guild_member(guild_id, user_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Edit a guild member and returns the updated [`GuildMember`], it's recommended to use the
+    /// [`MemberEdit`] builder. Each field requires its own permission.
+    /// Fires a [`GuildMemberUpdate`] event.
+    ///
+    /// [`GuildMember`]: ../../panda/models/guild/struct.GuildMember.html
+    /// [`MemberEdit`]: ../../panda/utils/builders/struct.MemberEdit.html
+    /// [`GuildMemberUpdate`]: ../../panda/models/gateway/events/struct.GuildMemberUpdate.html
+    pub async fn modify_guild_member(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        body: impl Serialize,
+    ) -> Result<GuildMember> {
+        let body = serde_json::to_string(&body)?;
+        let route = Route::modify_guild_member(guild_id, user_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Timeout a member until the given time (up to 28 days in the future), they can't send
+    /// messages, react or join voice channels until then. `None` removes the timeout.
+    /// Requires the **MODERATE_MEMBERS** permission. Returns the updated [`GuildMember`], and
+    /// fires a [`GuildMemberUpdate`] event.
+    ///
+    /// [`GuildMember`]: ../../panda/models/guild/struct.GuildMember.html
+    /// [`GuildMemberUpdate`]: ../../panda/models/gateway/events/struct.GuildMemberUpdate.html
+    pub async fn timeout_member(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        until: Option<SystemTime>,
+    ) -> Result<GuildMember> {
+        let until = match until {
+            Some(until) => {
+                if until > SystemTime::now() + MAX_TIMEOUT {
+                    return Err(PandaError::InvalidInput(
+                        "the timeout can't be more than 28 days in the future".into(),
+                    ));
+                }
+
+                Some(iso8601(until)?)
+            }
+            None => None,
+        };
+
+        let body = serde_json::json!({ "communication_disabled_until": until });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::modify_guild_member(guild_id, user_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Set the nickname of the bot in the guild, an empty string resets it.
+    /// Requires the **CHANGE_NICKNAME** permission. Fires a [`GuildMemberUpdate`] event.
+    ///
+    /// [`GuildMemberUpdate`]: ../../panda/models/gateway/events/struct.GuildMemberUpdate.html
+    pub async fn set_own_nick(&self, guild_id: impl AsRef<str>, nick: &str) -> Result<()> {
+        let body = serde_json::json!({ "nick": nick });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::modify_current_user_nick(guild_id, body);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Remove a member from a guild, the reason will be shown in the audit log.
+    /// Requires **KICK_MEMBERS** permission. Fires a [`GuildMemberRemove`] event.
+    ///
+    /// [`GuildMemberRemove`]: ../../panda/models/gateway/events/struct.GuildMemberRemove.html
+    pub async fn kick_member(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let route = Route::remove_guild_member(guild_id, user_id);
+
+        let _res = self._make_request_with_reason(route, reason).await?;
+
+        Ok(())
+    }
+
+    /// Returns a Vec<[`Ban`]> with the bans of the guild. Requires **BAN_MEMBERS** permission.
+    ///
+    /// [`Ban`]: ../../panda/models/guild/struct.Ban.html
+    pub async fn get_guild_bans(&self, guild_id: impl AsRef<str>) -> Result<Vec<Ban>> {
+        let route = Route::get_guild_bans(guild_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns the [`Ban`] of the user, or a `PandaError::Api` error if the user isn't banned.
+    /// Requires **BAN_MEMBERS** permission.
+    ///
+    /// [`Ban`]: ../../panda/models/guild/struct.Ban.html
+    pub async fn get_guild_ban(&self, guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Result<Ban> {
+        let route = Route::get_guild_ban(guild_id, user_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Create a guild ban, the [`BanOptions`] can delete the messages of the last days (0-7) sent
+    /// by the banned user, and set the reason shown in the audit log. It returns
+    /// `PandaError::InvalidInput` if the number of days is bigger than 7.
+    /// Requires **BAN_MEMBERS** permission. Fires a [`GuildBanAdd`] event.
+    ///
+    /// [`BanOptions`]: ../../panda/utils/builders/struct.BanOptions.html
+    /// [`GuildBanAdd`]: ../../panda/models/gateway/events/struct.GuildBanAdd.html
+    pub async fn ban_member(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        options: BanOptions,
+    ) -> Result<()> {
+        options.check()?;
+
+        let body = serde_json::to_string(&options)?;
+        let route = Route::create_guild_ban(guild_id, user_id, body);
+
+        let _res = self._make_request_with_reason(route, options.reason.as_deref()).await?;
+
+        Ok(())
+    }
+
+    /// Remove the ban for a user, the reason will be shown in the audit log.
+    /// Requires **BAN_MEMBERS** permission. Fires a [`GuildBanRemove`] event.
+    ///
+    /// [`GuildBanRemove`]: ../../panda/models/gateway/events/struct.GuildBanRemove.html
+    pub async fn unban_member(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let route = Route::remove_guild_ban(guild_id, user_id);
+
+        let _res = self._make_request_with_reason(route, reason).await?;
+
+        Ok(())
+    }
+
+    /// Returns the number of members that would be removed by a prune of `days` days (1-30), the
+    /// members that haven't been seen in that time and don't have roles.
+    /// Requires the **KICK_MEMBERS** permission.
+    pub async fn get_prune_count(&self, guild_id: impl AsRef<str>, days: u8) -> Result<u64> {
+        check_prune_days(days)?;
+
+        let route = Route::get_guild_prune_count(guild_id, days);
+
+        let mut res = self._make_request(route).await?;
+        let body: PruneBody = res.json().map_err(PandaError::Deserialize)?;
+
+        Ok(body.pruned.unwrap_or(0))
+    }
+
+    /// Removes the members that haven't been seen in `days` days (1-30) and don't have roles.
+    /// If `compute_count` is true, it returns the number of removed members, it's recommended
+    /// to set it to false for large guilds. Requires the **KICK_MEMBERS** permission.
+    /// Fires a [`GuildMemberRemove`] event for each removed member.
+    ///
+    /// [`GuildMemberRemove`]: ../../panda/models/gateway/events/struct.GuildMemberRemove.html
+    pub async fn begin_prune(&self, guild_id: impl AsRef<str>, days: u8, compute_count: bool) -> Result<Option<u64>> {
+        check_prune_days(days)?;
+
+        let body = serde_json::json!({ "days": days, "compute_prune_count": compute_count });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::begin_guild_prune(guild_id, body);
+
+        let mut res = self._make_request(route).await?;
+        let body: PruneBody = res.json().map_err(PandaError::Deserialize)?;
+
+        Ok(body.pruned)
+    }
+
+    /// Adds a role to a guild member. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`GuildMemberUpdate`] event.
+    ///
+    /// [`GuildMemberUpdate`]: ../../panda/models/gateway/events/struct.GuildMemberUpdate.html
+    pub async fn add_member_role(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let route = Route::add_guild_member_role(guild_id, user_id, role_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Removes a role from a guild member. Requires the **MANAGE_ROLES** permission.
+    /// Fires a [`GuildMemberUpdate`] event.
+    ///
+    /// [`GuildMemberUpdate`]: ../../panda/models/gateway/events/struct.GuildMemberUpdate.html
+    pub async fn remove_member_role(
+        &self,
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Result<()> {
+        let route = Route::remove_guild_member_role(guild_id, user_id, role_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    // *******************************************************************************
+    // * EMOJI METHODS
+    // *******************************************************************************
+
+    /// Returns a Vec<[`Emoji`]> with the custom emojis of the guild
+    ///
+    /// [`Emoji`]: ../../panda/models/emoji/struct.Emoji.html
+    pub async fn get_guild_emojis(&self, guild_id: impl AsRef<str>) -> Result<Vec<Emoji>> {
+        let route = Route::list_guild_emojis(guild_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Creates a custom emoji in the guild with the given PNG, JPEG or GIF image (up to 256KB),
+    /// and returns the new [`Emoji`]. If `roles` isn't empty, only the members with these roles
+    /// can use the emoji. Requires the **MANAGE_EMOJIS** permission.
+    /// Fires a [`GuildEmojisUpdate`] event.
+    ///
+    /// [`Emoji`]: ../../panda/models/emoji/struct.Emoji.html
+    /// [`GuildEmojisUpdate`]: ../../panda/models/gateway/events/struct.GuildEmojisUpdate.html
+    pub async fn create_guild_emoji(
+        &self,
+        guild_id: impl AsRef<str>,
+        name: &str,
+        image: &[u8],
+        roles: &[&str],
+    ) -> Result<Emoji> {
+        let image = base64::image_data_uri(image);
+        let body = serde_json::json!({ "name": name, "image": image, "roles": roles });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::create_guild_emoji(guild_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Deletes a custom emoji of the guild. Requires the **MANAGE_EMOJIS** permission.
+    /// Fires a [`GuildEmojisUpdate`] event.
+    ///
+    /// [`GuildEmojisUpdate`]: ../../panda/models/gateway/events/struct.GuildEmojisUpdate.html
+    pub async fn delete_guild_emoji(&self, guild_id: impl AsRef<str>, emoji_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::delete_guild_emoji(guild_id, emoji_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    // *******************************************************************************
+    // * USER METHODS
+    // *******************************************************************************
+
+    /// Returns the [`User`] of the bot
+    ///
+    /// [`User`]: ../../panda/models/user/struct.User.html
+    pub async fn get_current_user(&self) -> Result<User> {
+        let route = Route::get_current_user();
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns a [`User`] for a given user ID
+    ///
+    /// [`User`]: ../../panda/models/user/struct.User.html
+    pub async fn get_user(&self, user_id: impl AsRef<str>) -> Result<User> {
+        let route = Route::get_user(user_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns a Vec<[`PartialGuild`]> with the guilds of the bot, sorted by ID. Use `before` or
+    /// `after` with the ID of a guild of the previous page to get the next one, and `limit` for
+    /// the max number of guilds to return (1-100, 100 by default).
+    ///
+    /// [`PartialGuild`]: ../../panda/models/guild/struct.PartialGuild.html
+    pub async fn get_current_user_guilds(
+        &self,
+        before: Option<&str>,
+        after: Option<&str>,
+        limit: Option<u8>,
+    ) -> Result<Vec<PartialGuild>> {
+        if let Some(limit) = limit {
+            if limit == 0 || limit > 100 {
+                return Err(PandaError::InvalidInput(format!(
+                    "the guilds limit must be between 1 and 100, but it was {}",
+                    limit
+                )));
+            }
+        }
+
+        let route = Route::get_current_user_guilds(before, after, limit);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Leave the given guild, fires a [`GuildDelete`] event
+    ///
+    /// [`GuildDelete`]: ../../panda/models/gateway/events/struct.GuildDelete.html
+    pub async fn leave_guild(&self, guild_id: impl AsRef<str>) -> Result<()> {
+        let route = Route::leave_guild(guild_id);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    /// Opens a DM with the given user, and returns the DM [`Channel`], it can be used to send
+    /// messages to the user. If the DM already exists, the same channel is returned.
+    ///
+    /// [`Channel`]: ../../panda/models/channel/struct.Channel.html
+    pub async fn create_dm(&self, user_id: impl AsRef<str>) -> Result<Channel> {
+        let body = serde_json::json!({ "recipient_id": user_id.as_ref() });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::create_dm(body);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    // *******************************************************************************
+    // * INVITE METHODS
+    // *******************************************************************************
+
+    /// Returns the [`Invite`] for the given code, with the approximate member counts
+    ///
+    /// [`Invite`]: ../../panda/models/invite/struct.Invite.html
+    pub async fn get_invite(&self, code: impl AsRef<str>) -> Result<Invite> {
+        let route = Route::get_invite(code);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Delete an invite, and returns the deleted [`Invite`]. Requires the **MANAGE_CHANNELS**
+    /// permission on the channel, or **MANAGE_GUILD** to remove any invite of the guild.
+    /// Fires an [`InviteDelete`] event.
+    ///
+    /// [`Invite`]: ../../panda/models/invite/struct.Invite.html
+    /// [`InviteDelete`]: ../../panda/models/gateway/events/struct.InviteDelete.html
+    pub async fn delete_invite(&self, code: impl AsRef<str>) -> Result<Invite> {
+        let route = Route::delete_invite(code);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    // *******************************************************************************
+    // * WEBHOOK METHODS
+    // *******************************************************************************
+
+    /// Create a new webhook in the channel, and returns the [`Webhook`] with its token.
+    /// Requires the **MANAGE_WEBHOOKS** permission.
+    ///
+    /// [`Webhook`]: ../../panda/models/webhook/struct.Webhook.html
+    pub async fn create_webhook(&self, channel_id: impl AsRef<str>, name: impl AsRef<str>) -> Result<Webhook> {
+        let body = serde_json::json!({ "name": name.as_ref() });
+        let body = serde_json::to_string(&body)?;
+
+        let route = Route::create_webhook(channel_id, body);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Returns a Vec<[`Webhook`]> of the channel webhooks. Requires the **MANAGE_WEBHOOKS** permission.
+    ///
+    /// [`Webhook`]: ../../panda/models/webhook/struct.Webhook.html
+    pub async fn get_channel_webhooks(&self, channel_id: impl AsRef<str>) -> Result<Vec<Webhook>> {
+        let route = Route::get_channel_webhooks(channel_id);
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+
+    /// Sends a message with the webhook, the [`WebhookExecuteBuilder`] can override the username
+    /// and the avatar of the webhook for this message.
+    /// Webhooks have their own rate limits, separated from the bot ones.
+    ///
+    /// [`WebhookExecuteBuilder`]: ../../panda/utils/builders/struct.WebhookExecuteBuilder.html
+    pub async fn execute_webhook(
+        &self,
+        webhook_id: impl AsRef<str>,
+        token: impl AsRef<str>,
+        message: WebhookExecuteBuilder,
+    ) -> Result<()> {
+        let body = serde_json::to_string(&message)?;
+        let route = Route::execute_webhook(webhook_id, token, body);
+
+        let _res = self._make_request(route).await?;
+
+        Ok(())
+    }
+
+    // *******************************************************************************
+    // * CDN METHODS
+    // *******************************************************************************
+
+    /// Downloads the file of an [`Attachment`] and returns its bytes. The attachment URLs are
+    /// signed by Discord, so the request is sent without the token and it isn't rate limited.
+    ///
+    /// [`Attachment`]: ../../panda/models/channel/struct.Attachment.html
+    pub async fn download_attachment(&self, attachment: &Attachment) -> Result<Vec<u8>> {
+        let mut res = self.client.get_async(attachment.url.as_str()).await?;
+        self._catch_http_errors(&mut res)?;
+
+        let mut bytes = Vec::with_capacity(attachment.size as usize);
+        res.body_mut()
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|_| PandaError::HttpNoResponse)?;
+
+        Ok(bytes)
+    }
+
+    // *******************************************************************************
+    // * GATEWAY METHODS
+    // *******************************************************************************
+
+    /// Returns a [`GatewayBot`] with the recommended number of shards and the session start limit
+    ///
+    /// [`GatewayBot`]: ../../panda/models/gateway/struct.GatewayBot.html
+    pub async fn get_gateway_bot(&self) -> Result<GatewayBot> {
+        let route = Route::get_gateway_bot();
+
+        let mut res = self._make_request(route).await?;
+
+        res.json().map_err(PandaError::Deserialize)
+    }
+}
+
+/// Returns false if the message is older than 14 days, Discord doesn't bulk delete them.
+/// Invalid ids are sent to Discord, which returns the error
+fn is_bulk_deletable(message_id: &str) -> bool {
+    let id = match message_id.parse::<Snowflake>() {
+        Ok(id) => id,
+        Err(_) => return true,
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+
+    now.saturating_sub(id.timestamp()) < BULK_DELETE_MAX_AGE.as_millis() as u64
+}
+
+/// Discord only accepts prunes between 1 and 30 days
+fn check_prune_days(days: u8) -> Result<()> {
+    if days == 0 || days > 30 {
+        return Err(PandaError::InvalidInput(format!(
+            "the prune days must be between 1 and 30, but it was {}",
+            days
+        )));
+    }
+
+    Ok(())
+}
+
+/// Formats the time as an ISO8601 timestamp in UTC, like `2021-12-24T18:30:00.000Z`
+fn iso8601(time: SystemTime) -> Result<String> {
+    let since_epoch = time
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| PandaError::InvalidInput("the time can't be before 1970".into()))?;
+
+    let secs = since_epoch.as_secs();
+    let (days, secs_of_day) = (secs / 86400, secs % 86400);
+
+    // Converts the days since 1970-01-01 to a date of the proleptic gregorian calendar
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    Ok(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60,
+        since_epoch.subsec_millis()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iso8601_format() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_640_370_600_250);
+        assert_eq!(iso8601(time).unwrap(), "2021-12-24T18:30:00.250Z");
+
+        let leap_day = UNIX_EPOCH + Duration::from_secs(951_782_400);
+        assert_eq!(iso8601(leap_day).unwrap(), "2000-02-29T00:00:00.000Z");
+
+        assert_eq!(iso8601(UNIX_EPOCH).unwrap(), "1970-01-01T00:00:00.000Z");
+    }
 }
@@ -0,0 +1,77 @@
+use isahc::Body;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A `multipart/form-data` body, used to upload files
+#[derive(Clone)]
+pub(crate) struct Multipart {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl Multipart {
+    pub(crate) fn new() -> Multipart {
+        // The boundary only needs to be unlikely to appear in the files
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+
+        Multipart {
+            boundary: format!("----panda-boundary-{:x}", nanos),
+            body: Vec::new(),
+        }
+    }
+
+    /// Returns the Content-Type header of the body, with the boundary
+    pub(crate) fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// Adds a text part
+    pub(crate) fn add_text(&mut self, name: &str, value: &str) {
+        self.add_part_header(name, None, "text/plain; charset=utf-8");
+        self.body.extend_from_slice(value.as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+    }
+
+    /// Adds a file part
+    pub(crate) fn add_file(&mut self, name: &str, filename: &str, data: &[u8]) {
+        self.add_part_header(name, Some(filename), "application/octet-stream");
+        self.body.extend_from_slice(data);
+        self.body.extend_from_slice(b"\r\n");
+    }
+
+    /// Adds the closing boundary, no part can be added after it
+    pub(crate) fn finish(mut self) -> Multipart {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+
+        self
+    }
+
+    fn add_part_header(&mut self, name: &str, filename: Option<&str>, content_type: &str) {
+        let mut disposition = format!("form-data; name=\"{}\"", escape(name));
+
+        if let Some(filename) = filename {
+            disposition.push_str(&format!("; filename=\"{}\"", escape(filename)));
+        }
+
+        let header = format!(
+            "--{}\r\nContent-Disposition: {}\r\nContent-Type: {}\r\n\r\n",
+            self.boundary, disposition, content_type
+        );
+
+        self.body.extend_from_slice(header.as_bytes());
+    }
+}
+
+impl From<Multipart> for Body {
+    fn from(multipart: Multipart) -> Body {
+        Body::from(multipart.body)
+    }
+}
+
+// Quotes and line breaks would break the part header
+fn escape(value: &str) -> String {
+    value.replace('"', "%22").replace('\r', "%0D").replace('\n', "%0A")
+}
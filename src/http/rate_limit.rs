@@ -2,77 +2,213 @@ use crate::runtime;
 use std::{
     collections::HashMap,
     default::Default,
+    str::FromStr,
     sync::Arc,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant},
 };
 
 use futures::lock::Mutex;
 
-use isahc::{http::Response, Body};
+use isahc::{
+    http::{HeaderMap, Response, StatusCode},
+    Body,
+};
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub(crate) struct RateLimit {
-    // Key needs to be {major_parameter}:{channel_id/guild_id}
+    // Key is "{METHOD} {route template}:{major parameter}", created with `bucket_key!`
     // Example:
-    // - "channels:639562328521703445"
-    // - "channels/messages:639562328521703445"
+    // - "POST /channels/{channel.id}/messages:639562328521703445"
+    // - "GET /guilds/{guild.id}/members:639562328521703445"
+    // - "GET /users/@me" (routes without a major parameter)
     buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+    // When Discord sends a global 429, all the requests wait until this time
+    global_reset: Arc<Mutex<Option<Instant>>>,
 }
 
 #[derive(Default)]
 struct Bucket {
     pub limit: u32,
     pub remaining: u32,
-    pub reset: u64,
+    pub reset: Option<Instant>,
 }
 
 impl RateLimit {
+    /// Waits until the bucket has capacity for one more request, and takes it
     pub(crate) async fn check_and_sleep(&self, bucket_key: &str) {
-        // Get bucket from key
-        let mut buckets_hm = self.buckets.lock().await;
-        let bucket = buckets_hm.get_mut(bucket_key);
-
-        // If it exists, check
-        // If not exists, we assume that it's safe to make the api call
-        if let Some(b) = bucket {
-            // Get current time
-            let current = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
-            // Sleep reset time
-            let difference = Duration::from_secs(b.reset.saturating_sub(current));
-
-            if difference.as_secs() == 0 {
-                // We assume that we have waited the reset time
-                // but the reset value have not been updated
-                b.remaining += 1;
-            } else if b.remaining == 0 {
-                runtime::sleep(difference).await;
-
-                // Add the default remaining
-                b.remaining = b.limit;
+        // First wait the global rate limit, it affects all the buckets
+        let global_wait = self.global_reset.lock().await.and_then(|reset| {
+            let now = Instant::now();
+            if reset > now {
+                Some(reset - now)
+            } else {
+                None
             }
-            // If passes all then it's safe to make the api call
+        });
+
+        if let Some(wait) = global_wait {
+            runtime::sleep(wait).await;
+        }
+
+        loop {
+            let wait = {
+                // Get bucket from key
+                let mut buckets_hm = self.buckets.lock().await;
+
+                // If not exists, we assume that it's safe to make the api call
+                let b = match buckets_hm.get_mut(bucket_key) {
+                    Some(b) => b,
+                    None => return,
+                };
+
+                let now = Instant::now();
+
+                match b.reset {
+                    // The bucket is exhausted, wait until it resets
+                    Some(reset) if reset > now && b.remaining == 0 => reset - now,
+                    // There is capacity left
+                    Some(reset) if reset > now => {
+                        b.remaining -= 1;
+                        return;
+                    }
+                    // The reset time has passed, so the bucket is full again
+                    _ => {
+                        b.reset = None;
+                        b.remaining = b.limit.saturating_sub(1);
+                        return;
+                    }
+                }
+            };
 
-            b.remaining -= 1;
+            // The lock is released while sleeping, so other buckets aren't blocked
+            runtime::sleep(wait).await;
         }
     }
 
-    pub(crate) async fn update(&self, bucket_key: String, response: &Response<Body>) {
+    /// Updates the bucket with the `X-RateLimit-*` headers of the response. If the response is a 429
+    /// the bucket is emptied until the `Retry-After` time, and the time to wait is returned
+    pub(crate) async fn update(&self, bucket_key: String, response: &Response<Body>) -> Option<Duration> {
         let headers = response.headers();
         let mut buckets_hm = self.buckets.lock().await;
 
         // Get the bucket and update all entries
         let bucket = buckets_hm.entry(bucket_key).or_default();
 
-        if let Some(limit) = headers.get("x-ratelimit-limit") {
-            bucket.limit = limit.to_str().unwrap().parse::<u32>().unwrap();
+        if let Some(limit) = parse_header(headers, "x-ratelimit-limit") {
+            bucket.limit = limit;
         }
 
-        if let Some(remaining) = headers.get("x-ratelimit-remaining") {
-            bucket.remaining = remaining.to_str().unwrap().parse::<u32>().unwrap();
+        if let Some(remaining) = parse_header(headers, "x-ratelimit-remaining") {
+            bucket.remaining = remaining;
         }
 
-        if let Some(reset) = headers.get("x-ratelimit-reset") {
-            bucket.reset = reset.to_str().unwrap().parse::<u64>().unwrap();
+        // Reset-After is relative, so it doesn't depend on the local clock being in sync with Discord
+        if let Some(reset_after) = parse_header::<f64>(headers, "x-ratelimit-reset-after") {
+            bucket.reset = Some(Instant::now() + Duration::from_secs_f64(reset_after.max(0.0)));
         }
+
+        if response.status() != StatusCode::TOO_MANY_REQUESTS {
+            return None;
+        }
+
+        // Discord sends Retry-After in seconds, if it's missing wait one second
+        let retry_after = parse_header::<f64>(headers, "retry-after")
+            .map(|secs| Duration::from_secs_f64(secs.max(0.0)))
+            .unwrap_or_else(|| Duration::from_secs(1));
+
+        // A global rate limit pauses all the requests, not only the ones of this bucket
+        if parse_header::<bool>(headers, "x-ratelimit-global").unwrap_or(false) {
+            *self.global_reset.lock().await = Some(Instant::now() + retry_after);
+        } else {
+            bucket.remaining = 0;
+            bucket.reset = Some(Instant::now() + retry_after);
+        }
+
+        Some(retry_after)
+    }
+}
+
+/// Parses a header value, returns None if the header is missing or malformed
+fn parse_header<T: FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn global_rate_limit_blocks_other_buckets() {
+        let rate_limit = RateLimit::default();
+        let response = Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header("X-RateLimit-Global", "true")
+            .header("Retry-After", "0.2")
+            .body(Body::empty())
+            .unwrap();
+
+        runtime::block_on(async {
+            let retry_after = rate_limit
+                .update("POST /channels/{channel.id}/messages:1".into(), &response)
+                .await;
+            assert_eq!(retry_after, Some(Duration::from_millis(200)));
+
+            let start = Instant::now();
+            rate_limit.check_and_sleep("GET /guilds/{guild.id}:2").await;
+            let waited = start.elapsed();
+
+            assert!(waited >= Duration::from_millis(180), "waited {:?}", waited);
+            assert!(waited < Duration::from_secs(1), "waited {:?}", waited);
+        });
+    }
+
+    fn exhausted_bucket() -> Response<Body> {
+        Response::builder()
+            .status(StatusCode::OK)
+            .header("X-RateLimit-Limit", "5")
+            .header("X-RateLimit-Remaining", "0")
+            .header("X-RateLimit-Reset-After", "0.2")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[test]
+    fn exhausted_bucket_delays_next_request() {
+        let rate_limit = RateLimit::default();
+        let bucket_key = "POST /channels/{channel.id}/messages:1";
+
+        runtime::block_on(async {
+            let retry_after = rate_limit.update(bucket_key.into(), &exhausted_bucket()).await;
+            assert_eq!(retry_after, None);
+
+            let start = Instant::now();
+            rate_limit.check_and_sleep(bucket_key).await;
+            let waited = start.elapsed();
+
+            assert!(waited >= Duration::from_millis(180), "waited {:?}", waited);
+            assert!(waited < Duration::from_secs(1), "waited {:?}", waited);
+        });
+    }
+
+    #[test]
+    fn exhausted_bucket_does_not_delay_other_buckets() {
+        let rate_limit = RateLimit::default();
+
+        runtime::block_on(async {
+            rate_limit
+                .update("POST /channels/{channel.id}/messages:1".into(), &exhausted_bucket())
+                .await;
+
+            let start = Instant::now();
+            rate_limit
+                .check_and_sleep("POST /channels/{channel.id}/messages:2")
+                .await;
+            rate_limit
+                .check_and_sleep("GET /channels/{channel.id}/messages:1")
+                .await;
+            let waited = start.elapsed();
+
+            assert!(waited < Duration::from_millis(50), "waited {:?}", waited);
+        });
     }
 }
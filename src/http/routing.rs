@@ -1,24 +1,53 @@
-use isahc::{http::Method, Body};
+use super::multipart::Multipart;
+use crate::utils::{builders::MessageQuery, percent_encode_component};
 
-// const DISCORD_URL: &'static str = "https://discord.com/api/v6";
+use isahc::{http::Method, Body};
 
+/// Creates the rate limit bucket of a route, Discord limits each route (method and path) on its
+/// own, and separately for each major parameter (the channel, guild or webhook of the route)
 macro_rules! bucket_key {
-    (channel: $id: expr) => {
-        format!("channels:{}", $id.as_ref());
+    ($method: expr, $route: expr) => {
+        format!("{} {}", $method, $route)
     };
-    (guild: $id: expr) => {
-        format!("guild:{}", $id.as_ref());
-    };
-    (emoji: $id: expr) => {
-        format!("emoji:{}", $id.as_ref());
+    ($method: expr, $route: expr, $major: expr) => {
+        format!("{} {}:{}", $method, $route, $major.as_ref())
     };
 }
 
+/// Creates the path of a request, the HttpClient adds the base URL of the API before it
 macro_rules! api_request {
     ($url: expr, $($rest: expr),*) => {
-        format!(concat!("https://discord.com/api/v6", $url), $($rest),*)
+        format!($url, $($rest),*)
     };
 }
+/// A request body, it knows the Content-Type header to send with it
+pub(crate) trait RequestBody: Into<Body> + Clone {
+    /// Returns None for requests without body
+    fn content_type(&self) -> Option<String>;
+}
+
+impl RequestBody for () {
+    fn content_type(&self) -> Option<String> {
+        None
+    }
+}
+
+impl RequestBody for String {
+    fn content_type(&self) -> Option<String> {
+        if self.is_empty() {
+            None
+        } else {
+            Some("application/json".into())
+        }
+    }
+}
+
+impl RequestBody for Multipart {
+    fn content_type(&self) -> Option<String> {
+        Some(Multipart::content_type(self))
+    }
+}
+
 pub(crate) struct Route<B> {
     pub(crate) method: Method,
     pub(crate) uri: String,
@@ -32,7 +61,7 @@ impl Route<()> {
     pub(crate) fn get_channel(channel_id: impl AsRef<str>) -> Self {
         let method = Method::GET;
         let uri = api_request!("/channels/{}", channel_id.as_ref());
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}", channel_id);
 
         Route {
             method,
@@ -46,7 +75,7 @@ impl Route<()> {
     pub(crate) fn delete_channel(channel_id: impl AsRef<str>) -> Self {
         let method = Method::DELETE;
         let uri = api_request!("/channels/{}", channel_id.as_ref());
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}", channel_id);
 
         Route {
             method,
@@ -57,21 +86,17 @@ impl Route<()> {
     }
 
     // GET/channels/{channel.id}/messages
-    pub(crate) fn get_channel_messages(
-        kind: &str,
-        channel_id: impl AsRef<str>,
-        message_id: impl AsRef<str>,
-        limit: u8,
-    ) -> Self {
+    pub(crate) fn get_channel_messages(channel_id: impl AsRef<str>, query: &MessageQuery) -> Self {
         let method = Method::GET;
-        let uri = api_request!(
-            "/channels/{}/messages?{}={}&limit={}",
-            channel_id.as_ref(),
-            kind,
-            message_id.as_ref(),
-            limit
-        );
-        let bucket_key = bucket_key!(channel: channel_id);
+        let mut uri = api_request!("/channels/{}/messages", channel_id.as_ref());
+
+        let query = query.to_query();
+        if !query.is_empty() {
+            uri.push('?');
+            uri.push_str(&query);
+        }
+
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/messages", channel_id);
 
         Route {
             method,
@@ -85,7 +110,7 @@ impl Route<()> {
     pub(crate) fn get_channel_message(channel_id: impl AsRef<str>, msg_id: impl AsRef<str>) -> Self {
         let method = Method::GET;
         let uri = api_request!("/channels/{}/messages/{}", channel_id.as_ref(), msg_id.as_ref());
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/messages/{message.id}", channel_id);
 
         Route {
             method,
@@ -102,14 +127,18 @@ impl Route<()> {
         emoji: impl AsRef<str>,
     ) -> Self {
         let method = Method::PUT;
-        let emoji = encode(emoji);
+        let emoji = percent_encode_component(emoji);
         let uri = api_request!(
             "/channels/{}/messages/{}/reactions/{}/@me",
             channel_id.as_ref(),
             msg_id.as_ref(),
             emoji
         );
-        let bucket_key = bucket_key!(emoji: channel_id);
+        let bucket_key = bucket_key!(
+            method,
+            "/channels/{channel.id}/messages/{message.id}/reactions/{emoji}",
+            channel_id
+        );
 
         Route {
             method,
@@ -126,14 +155,18 @@ impl Route<()> {
         emoji: impl AsRef<str>,
     ) -> Self {
         let method = Method::DELETE;
-        let emoji = encode(emoji);
+        let emoji = percent_encode_component(emoji);
         let uri = api_request!(
             "/channels/{}/messages/{}/reactions/{}/@me",
             channel_id.as_ref(),
             msg_id.as_ref(),
             emoji
         );
-        let bucket_key = bucket_key!(emoji: channel_id);
+        let bucket_key = bucket_key!(
+            method,
+            "/channels/{channel.id}/messages/{message.id}/reactions/{emoji}/@me",
+            channel_id
+        );
 
         Route {
             method,
@@ -151,7 +184,7 @@ impl Route<()> {
         user_id: impl AsRef<str>,
     ) -> Self {
         let method = Method::DELETE;
-        let emoji = encode(emoji);
+        let emoji = percent_encode_component(emoji);
         let uri = api_request!(
             "/channels/{}/messages/{}/reactions/{}/{}",
             channel_id.as_ref(),
@@ -160,7 +193,11 @@ impl Route<()> {
             user_id.as_ref()
         );
 
-        let bucket_key = bucket_key!(emoji: channel_id);
+        let bucket_key = bucket_key!(
+            method,
+            "/channels/{channel.id}/messages/{message.id}/reactions/{emoji}/{user.id}",
+            channel_id
+        );
 
         Route {
             method,
@@ -171,16 +208,35 @@ impl Route<()> {
     }
 
     // GET/channels/{channel.id}/messages/{message.id}/reactions/{emoji}
-    pub(crate) fn get_reactions(channel_id: impl AsRef<str>, msg_id: impl AsRef<str>, emoji: impl AsRef<str>) -> Self {
+    pub(crate) fn get_reactions(
+        channel_id: impl AsRef<str>,
+        msg_id: impl AsRef<str>,
+        emoji: impl AsRef<str>,
+        after: Option<&str>,
+        limit: Option<u8>,
+    ) -> Self {
         let method = Method::GET;
-        let emoji = encode(emoji);
-        let uri = api_request!(
-            "/channels/{}/messages/{}/reactions/{}",
+        let emoji = percent_encode_component(emoji);
+        let mut uri = api_request!(
+            "/channels/{}/messages/{}/reactions/{}?",
             channel_id.as_ref(),
             msg_id.as_ref(),
             emoji
         );
-        let bucket_key = bucket_key!(emoji: channel_id);
+
+        if let Some(after) = after {
+            uri.push_str(&format!("after={}&", percent_encode_component(after)));
+        }
+        if let Some(limit) = limit {
+            uri.push_str(&format!("limit={}&", limit));
+        }
+        uri.pop();
+
+        let bucket_key = bucket_key!(
+            method,
+            "/channels/{channel.id}/messages/{message.id}/reactions/{emoji}",
+            channel_id
+        );
 
         Route {
             method,
@@ -199,7 +255,11 @@ impl Route<()> {
             msg_id.as_ref()
         );
 
-        let bucket_key = bucket_key!(emoji: channel_id);
+        let bucket_key = bucket_key!(
+            method,
+            "/channels/{channel.id}/messages/{message.id}/reactions",
+            channel_id
+        );
 
         Route {
             method,
@@ -216,7 +276,7 @@ impl Route<()> {
         emoji: impl AsRef<str>,
     ) -> Self {
         let method = Method::DELETE;
-        let emoji = encode(emoji);
+        let emoji = percent_encode_component(emoji);
         let uri = api_request!(
             "/channels/{}/messages/{}/reactions/{}",
             channel_id.as_ref(),
@@ -224,7 +284,11 @@ impl Route<()> {
             emoji
         );
 
-        let bucket_key = bucket_key!(emoji: channel_id);
+        let bucket_key = bucket_key!(
+            method,
+            "/channels/{channel.id}/messages/{message.id}/reactions/{emoji}",
+            channel_id
+        );
 
         Route {
             method,
@@ -239,7 +303,7 @@ impl Route<()> {
         let method = Method::DELETE;
         let uri = api_request!("/channels/{}/messages/{}", channel_id.as_ref(), msg_id.as_ref());
 
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/messages/{message.id}", channel_id);
 
         Route {
             method,
@@ -254,7 +318,94 @@ impl Route<()> {
         let method = Method::POST;
         let uri = api_request!("/channels/{}/typing", channel_id.as_ref());
 
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/typing", channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // POST/channels/{channel.id}/messages/{message.id}/crosspost
+    pub(crate) fn crosspost_message(channel_id: impl AsRef<str>, msg_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::POST;
+        let uri = api_request!(
+            "/channels/{}/messages/{}/crosspost",
+            channel_id.as_ref(),
+            msg_id.as_ref()
+        );
+
+        let bucket_key = bucket_key!(
+            method,
+            "/channels/{channel.id}/messages/{message.id}/crosspost",
+            channel_id
+        );
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/invites/{invite.code}
+    pub(crate) fn get_invite(code: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/invites/{}?with_counts=true", percent_encode_component(code));
+
+        let bucket_key = bucket_key!(method, "/invites/{invite.code}");
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/invites/{invite.code}
+    pub(crate) fn delete_invite(code: impl AsRef<str>) -> Route<()> {
+        let method = Method::DELETE;
+        let uri = api_request!("/invites/{}", percent_encode_component(code));
+
+        let bucket_key = bucket_key!(method, "/invites/{invite.code}");
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/channels/{channel.id}/webhooks
+    pub(crate) fn get_channel_webhooks(channel_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/channels/{}/webhooks", channel_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/webhooks", channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/channels/{channel.id}/permissions/{overwrite.id}
+    pub(crate) fn delete_channel_permission(channel_id: impl AsRef<str>, overwrite_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::DELETE;
+        let uri = api_request!(
+            "/channels/{}/permissions/{}",
+            channel_id.as_ref(),
+            overwrite_id.as_ref()
+        );
+
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/permissions/{overwrite.id}", channel_id);
 
         Route {
             method,
@@ -269,7 +420,7 @@ impl Route<()> {
         let method = Method::GET;
         let uri = api_request!("/channels/{}/pins", channel_id.as_ref());
 
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/pins", channel_id);
 
         Route {
             method,
@@ -284,7 +435,7 @@ impl Route<()> {
         let method = Method::PUT;
         let uri = api_request!("/channels/{}/pins/{}", channel_id.as_ref(), msg_id.as_ref());
 
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/pins/{message.id}", channel_id);
 
         Route {
             method,
@@ -299,7 +450,7 @@ impl Route<()> {
         let method = Method::DELETE;
         let uri = api_request!("/channels/{}/pins/{}", channel_id.as_ref(), msg_id.as_ref());
 
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/pins/{message.id}", channel_id);
 
         Route {
             method,
@@ -308,143 +459,730 @@ impl Route<()> {
             body: (),
         }
     }
-}
 
-// Routes with body
-impl<B: Into<Body>> Route<B> {
-    // pub(crate) fn as_request(self, token: &str) -> (String, Request<Body>) {
-    //     // let request = match self.method {
-    //     //     Method::GET | Method::PUT | Method::DELETE => Request::builder()
-    //     //         .method(&self.method)
-    //     //         .uri(&self.uri)
-    //     //         .header("Authorization", token)
-    //     //         .body(())
-    //     //         .unwrap(),
-    //     //     Method::POST | Method::PATCH => Request::builder()
-    //     //         .method(&self.method)
-    //     //         .uri(&self.uri)
-    //     //         .header("Authorization", token)
-    //     //         .header("Content-Type", "application/json")
-    //     //         .body(self.body.into())
-    //     //         .unwrap(),
-    //     //     _ => unimplemented!(),
-    //     // };
+    // GET/users/@me
+    pub(crate) fn get_current_user() -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/users/@me",);
 
-    //     let request = match self.body {
-    //         () => Request::builder()
-    //             .method(&self.method)
-    //             .uri(&self.uri)
-    //             .header("Authorization", token)
-    //             .body(())
-    //             .unwrap(),
-    //         _ => Request::builder()
-    //             .method(&self.method)
-    //             .uri(&self.uri)
-    //             .header("Authorization", token)
-    //             .header("Content-Type", "application/json")
-    //             .body(self.body)
-    //             .unwrap(),
-    //     };
+        let bucket_key = bucket_key!(method, "/users/@me");
 
-    //     println!("{:?}", request);
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
 
-    //     (self.bucket_key, request)
-    // }
+    // GET/users/{user.id}
+    pub(crate) fn get_user(user_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/users/{}", user_id.as_ref());
 
-    // PATCH/channels/{channel.id}
-    pub(crate) fn edit_channel(channel_id: impl AsRef<str>, body: B) -> Self {
-        let method = Method::PATCH;
-        let uri = api_request!("/channels/{}", channel_id.as_ref());
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/users/{user.id}");
 
-        Self {
+        Route {
             method,
             uri,
             bucket_key,
-            body,
+            body: (),
         }
     }
 
-    // POST/channels/{channel.id}/messages
-    pub(crate) fn create_message(channel_id: impl AsRef<str>, body: B) -> Self {
-        let method = Method::POST;
-        let uri = api_request!("/channels/{}/messages", channel_id.as_ref());
-        let bucket_key = bucket_key!(channel: channel_id);
+    // GET/users/@me/guilds
+    pub(crate) fn get_current_user_guilds(before: Option<&str>, after: Option<&str>, limit: Option<u8>) -> Route<()> {
+        let method = Method::GET;
+        let mut uri = api_request!("/users/@me/guilds?",);
 
-        Self {
+        if let Some(before) = before {
+            uri.push_str(&format!("before={}&", percent_encode_component(before)));
+        }
+        if let Some(after) = after {
+            uri.push_str(&format!("after={}&", percent_encode_component(after)));
+        }
+        if let Some(limit) = limit {
+            uri.push_str(&format!("limit={}&", limit));
+        }
+        uri.pop();
+
+        let bucket_key = bucket_key!(method, "/users/@me/guilds");
+
+        Route {
             method,
             uri,
             bucket_key,
-            body,
+            body: (),
         }
     }
 
-    // PATCH/channels/{channel.id}/messages/{message.id}
-    pub(crate) fn edit_message(channel_id: impl AsRef<str>, msg_id: impl AsRef<str>, body: B) -> Self {
-        let method = Method::PATCH;
-        let uri = api_request!("/channels/{}/messages/{}", channel_id.as_ref(), msg_id.as_ref());
+    // DELETE/users/@me/guilds/{guild.id}
+    pub(crate) fn leave_guild(guild_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::DELETE;
+        let uri = api_request!("/users/@me/guilds/{}", guild_id.as_ref());
 
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/users/@me/guilds/{guild.id}");
 
-        Self {
+        Route {
             method,
             uri,
             bucket_key,
-            body,
+            body: (),
         }
     }
 
-    // POST/channels/{channel.id}/messages/bulk-delete
-    pub(crate) fn bulk_delete_messages(channel_id: impl AsRef<str>, body: B) -> Self {
-        let method = Method::POST;
-        let uri = api_request!("/channels/{}/messages/bulk-delete", channel_id.as_ref());
+    // GET/gateway/bot
+    pub(crate) fn get_gateway_bot() -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/gateway/bot",);
 
-        let bucket_key = bucket_key!(channel: channel_id);
+        let bucket_key = bucket_key!(method, "/gateway/bot");
 
         Route {
             method,
             uri,
             bucket_key,
-            body,
+            body: (),
         }
     }
 
-    // PUT/channels/{channel.id}/permissions/{overwrite.id}
-    // TODO: Check {overwrite.id}
-    // pub(crate) fn edit_channel_permissions(
-    //     channel_id: impl AsRef<str>,
-    //     overwrite_id: impl AsRef<str>,
-    //     body: B,
-    // ) -> Self {
-    //     let method = Method::PUT;
-    //     let uri = api_request!(
-    //         "/channels/{}/permissions/{}",
-    //         channel_id.as_ref(),
-    //         overwrite_id.as_ref()
-    //     );
-
-    //     let bucket_key = bucket_key!(channel: channel_id);
-
-    //     Route {
-    //         method,
-    //         uri,
-    //         bucket_key,
-    //         body,
-    //     }
-    // }
-}
+    // GET/guilds/{guild.id}
+    pub(crate) fn get_guild(guild_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/audit-logs
+    pub(crate) fn get_guild_audit_log(
+        guild_id: impl AsRef<str>,
+        user_id: Option<&str>,
+        action_type: Option<u16>,
+        limit: Option<u8>,
+    ) -> Route<()> {
+        let method = Method::GET;
+        let mut uri = api_request!("/guilds/{}/audit-logs?", guild_id.as_ref());
+
+        if let Some(user_id) = user_id {
+            uri.push_str(&format!("user_id={}&", percent_encode_component(user_id)));
+        }
+        if let Some(action_type) = action_type {
+            uri.push_str(&format!("action_type={}&", action_type));
+        }
+        if let Some(limit) = limit {
+            uri.push_str(&format!("limit={}&", limit));
+        }
+        uri.pop();
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/audit-logs", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/channels
+    pub(crate) fn get_guild_channels(guild_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/channels", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/channels", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/members
+    pub(crate) fn list_guild_members(guild_id: impl AsRef<str>, limit: u16, after: Option<&str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!(
+            "/guilds/{}/members?limit={}&after={}",
+            guild_id.as_ref(),
+            limit,
+            percent_encode_component(after.unwrap_or("0"))
+        );
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/members", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/members/search
+    pub(crate) fn search_guild_members(guild_id: impl AsRef<str>, query: &str, limit: u16) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!(
+            "/guilds/{}/members/search?query={}&limit={}",
+            guild_id.as_ref(),
+            percent_encode_component(query),
+            limit
+        );
 
-/// Used to encode emoji as a valid char in URL
-fn encode(data: impl AsRef<str>) -> String {
-    let mut escaped = String::new();
-    for b in data.as_ref().as_bytes().iter() {
-        match *b as char {
-            // Accepted characters
-            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => escaped.push(*b as char),
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/members/search", guild_id);
 
-            // Everything else is percent-encoded
-            b => escaped.push_str(format!("%{:02X}", b as u32).as_str()),
-        };
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/members/{user.id}
+    pub(crate) fn get_guild_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/members/{}", guild_id.as_ref(), user_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/members/{user.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/members/{user.id}
+    pub(crate) fn remove_guild_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::DELETE;
+        let uri = api_request!("/guilds/{}/members/{}", guild_id.as_ref(), user_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/members/{user.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/bans
+    pub(crate) fn get_guild_bans(guild_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/bans", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/bans", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/bans/{user.id}
+    pub(crate) fn get_guild_ban(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/bans/{}", guild_id.as_ref(), user_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/bans/{user.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/bans/{user.id}
+    pub(crate) fn remove_guild_ban(guild_id: impl AsRef<str>, user_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::DELETE;
+        let uri = api_request!("/guilds/{}/bans/{}", guild_id.as_ref(), user_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/bans/{user.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/prune
+    pub(crate) fn get_guild_prune_count(guild_id: impl AsRef<str>, days: u8) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/prune?days={}", guild_id.as_ref(), days);
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/prune", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // PUT/guilds/{guild.id}/members/{user.id}/roles/{role.id}
+    pub(crate) fn add_guild_member_role(
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Route<()> {
+        let method = Method::PUT;
+        let uri = api_request!(
+            "/guilds/{}/members/{}/roles/{}",
+            guild_id.as_ref(),
+            user_id.as_ref(),
+            role_id.as_ref()
+        );
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/members/{user.id}/roles/{role.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/members/{user.id}/roles/{role.id}
+    pub(crate) fn remove_guild_member_role(
+        guild_id: impl AsRef<str>,
+        user_id: impl AsRef<str>,
+        role_id: impl AsRef<str>,
+    ) -> Route<()> {
+        let method = Method::DELETE;
+        let uri = api_request!(
+            "/guilds/{}/members/{}/roles/{}",
+            guild_id.as_ref(),
+            user_id.as_ref(),
+            role_id.as_ref()
+        );
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/members/{user.id}/roles/{role.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/roles
+    pub(crate) fn get_guild_roles(guild_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/roles", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/roles", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/roles/{role.id}
+    pub(crate) fn delete_guild_role(guild_id: impl AsRef<str>, role_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::DELETE;
+        let uri = api_request!("/guilds/{}/roles/{}", guild_id.as_ref(), role_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/roles/{role.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // GET/guilds/{guild.id}/emojis
+    pub(crate) fn list_guild_emojis(guild_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::GET;
+        let uri = api_request!("/guilds/{}/emojis", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/emojis", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+
+    // DELETE/guilds/{guild.id}/emojis/{emoji.id}
+    pub(crate) fn delete_guild_emoji(guild_id: impl AsRef<str>, emoji_id: impl AsRef<str>) -> Route<()> {
+        let method = Method::DELETE;
+        let uri = api_request!("/guilds/{}/emojis/{}", guild_id.as_ref(), emoji_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/emojis/{emoji.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body: (),
+        }
+    }
+}
+
+// Routes with body
+impl<B: Into<Body>> Route<B> {
+    // pub(crate) fn as_request(self, token: &str) -> (String, Request<Body>) {
+    //     // let request = match self.method {
+    //     //     Method::GET | Method::PUT | Method::DELETE => Request::builder()
+    //     //         .method(&self.method)
+    //     //         .uri(&self.uri)
+    //     //         .header("Authorization", token)
+    //     //         .body(())
+    //     //         .unwrap(),
+    //     //     Method::POST | Method::PATCH => Request::builder()
+    //     //         .method(&self.method)
+    //     //         .uri(&self.uri)
+    //     //         .header("Authorization", token)
+    //     //         .header("Content-Type", "application/json")
+    //     //         .body(self.body.into())
+    //     //         .unwrap(),
+    //     //     _ => unimplemented!(),
+    //     // };
+
+    //     let request = match self.body {
+    //         () => Request::builder()
+    //             .method(&self.method)
+    //             .uri(&self.uri)
+    //             .header("Authorization", token)
+    //             .body(())
+    //             .unwrap(),
+    //         _ => Request::builder()
+    //             .method(&self.method)
+    //             .uri(&self.uri)
+    //             .header("Authorization", token)
+    //             .header("Content-Type", "application/json")
+    //             .body(self.body)
+    //             .unwrap(),
+    //     };
+
+    //     println!("{:?}", request);
+
+    //     (self.bucket_key, request)
+    // }
+
+    // PATCH/channels/{channel.id}
+    pub(crate) fn edit_channel(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/channels/{}", channel_id.as_ref());
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}", channel_id);
+
+        Self {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/channels/{channel.id}/messages
+    pub(crate) fn create_message(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/channels/{}/messages", channel_id.as_ref());
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/messages", channel_id);
+
+        Self {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/channels/{channel.id}/messages/{message.id}
+    pub(crate) fn edit_message(channel_id: impl AsRef<str>, msg_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/channels/{}/messages/{}", channel_id.as_ref(), msg_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/messages/{message.id}", channel_id);
+
+        Self {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/channels/{channel.id}/messages/bulk-delete
+    pub(crate) fn bulk_delete_messages(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/channels/{}/messages/bulk-delete", channel_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/messages/bulk-delete", channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/guilds/{guild.id}/prune
+    pub(crate) fn begin_guild_prune(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/guilds/{}/prune", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/prune", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PUT/guilds/{guild.id}/bans/{user.id}
+    pub(crate) fn create_guild_ban(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PUT;
+        let uri = api_request!("/guilds/{}/bans/{}", guild_id.as_ref(), user_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/bans/{user.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/guilds/{guild.id}/channels
+    pub(crate) fn create_guild_channel(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/guilds/{}/channels", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/channels", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/guilds/{guild.id}/roles
+    pub(crate) fn create_guild_role(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/guilds/{}/roles", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/roles", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/guilds/{guild.id}/emojis
+    pub(crate) fn create_guild_emoji(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/guilds/{}/emojis", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/emojis", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/members/{user.id}
+    pub(crate) fn modify_guild_member(guild_id: impl AsRef<str>, user_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/members/{}", guild_id.as_ref(), user_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/members/{user.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/members/@me/nick
+    pub(crate) fn modify_current_user_nick(guild_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/members/@me/nick", guild_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/members/@me/nick", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PATCH/guilds/{guild.id}/roles/{role.id}
+    pub(crate) fn modify_guild_role(guild_id: impl AsRef<str>, role_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::PATCH;
+        let uri = api_request!("/guilds/{}/roles/{}", guild_id.as_ref(), role_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/guilds/{guild.id}/roles/{role.id}", guild_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/channels/{channel.id}/invites
+    pub(crate) fn create_channel_invite(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/channels/{}/invites", channel_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/invites", channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/channels/{channel.id}/webhooks
+    pub(crate) fn create_webhook(channel_id: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/channels/{}/webhooks", channel_id.as_ref());
+
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/webhooks", channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/webhooks/{webhook.id}/{webhook.token}
+    pub(crate) fn execute_webhook(webhook_id: impl AsRef<str>, token: impl AsRef<str>, body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/webhooks/{}/{}", webhook_id.as_ref(), token.as_ref());
+
+        let bucket_key = bucket_key!(method, "/webhooks/{webhook.id}/{webhook.token}", webhook_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // POST/users/@me/channels
+    pub(crate) fn create_dm(body: B) -> Self {
+        let method = Method::POST;
+        let uri = api_request!("/users/@me/channels",);
+
+        let bucket_key = bucket_key!(method, "/users/@me/channels");
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+
+    // PUT/channels/{channel.id}/permissions/{overwrite.id}
+    pub(crate) fn edit_channel_permissions(
+        channel_id: impl AsRef<str>,
+        overwrite_id: impl AsRef<str>,
+        body: B,
+    ) -> Self {
+        let method = Method::PUT;
+        let uri = api_request!(
+            "/channels/{}/permissions/{}",
+            channel_id.as_ref(),
+            overwrite_id.as_ref()
+        );
+
+        let bucket_key = bucket_key!(method, "/channels/{channel.id}/permissions/{overwrite.id}", channel_id);
+
+        Route {
+            method,
+            uri,
+            bucket_key,
+            body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_channel_message_route() {
+        let route = Route::get_channel_message("1", "2");
+
+        assert_eq!(route.method, Method::GET);
+        assert_eq!(route.uri, "/channels/1/messages/2");
+        assert_eq!(route.bucket_key, "GET /channels/{channel.id}/messages/{message.id}:1");
+    }
+
+    #[test]
+    fn edit_message_route() {
+        let route = Route::edit_message("1", "2", String::from("{}"));
+
+        assert_eq!(route.method, Method::PATCH);
+        assert_eq!(route.uri, "/channels/1/messages/2");
+        assert_eq!(route.bucket_key, "PATCH /channels/{channel.id}/messages/{message.id}:1");
+    }
+
+    #[test]
+    fn get_reactions_route_encodes_emoji() {
+        let route = Route::get_reactions("1", "2", "panda:3", Some("4"), Some(100));
+
+        assert_eq!(route.method, Method::GET);
+        assert_eq!(
+            route.uri,
+            "/channels/1/messages/2/reactions/panda%3A3?after=4&limit=100"
+        );
+        assert_eq!(
+            route.bucket_key,
+            "GET /channels/{channel.id}/messages/{message.id}/reactions/{emoji}:1"
+        );
     }
-    return escaped;
 }
@@ -21,6 +21,13 @@
 //! ```
 //!
 //!
+//! # Blocking HTTP client
+//! The `blocking` feature adds the [`blocking`] module, a synchronous version of the
+//! [`HttpClient`], for programs that only need to make some requests.
+//!
+//! [`blocking`]: blocking/index.html
+//! [`HttpClient`]: struct.HttpClient.html
+//!
 //! # Example usage
 //! It will print the bot name when the bot is ready.
 //!
@@ -69,6 +76,8 @@ define_cfg! {
     pub mod models;
     #[doc(inline)]
     pub mod utils;
+    #[cfg(feature = "blocking")]
+    pub mod blocking;
 
     mod error;
     mod gateway;
@@ -83,9 +92,14 @@ define_cfg! {
 
     // Types
 
-    /// Alias for Result<(), Box<dyn std::error::Error>>
+    /// Alias for Result<(), Box<dyn std::error::Error>>, it's returned by the event handlers,
+    /// so they can use `?`. The errors are logged with the name of the event
     pub type HandlerResult = Result<(), Box<dyn std::error::Error>>;
 
+    /// Alias for BoxFuture<'a, HandlerResult>, it's returned by the methods of
+    /// [`EventHandler`](client/trait.EventHandler.html)
+    pub type HandlerFuture<'a> = futures::future::BoxFuture<'a, HandlerResult>;
+
     /// Alias for Arc<SessionData<S>>
     pub type Session<S> = std::sync::Arc<client::SessionData<S>>;
 
@@ -94,6 +108,11 @@ define_cfg! {
         client::Client::<()>::new(token).await
     }
 
+    /// Create a new panda Client without state and with personalized configs
+    pub async fn new_with_config(token: impl Into<String>, config: client::Config) -> error::Result<client::Client<()>> {
+        client::Client::<()>::new_with_config(token, config).await
+    }
+
     /// Create a new panda Client with state
     pub async fn new_with_state<S: Sync + Send>(token: impl Into<String>, state: S) -> error::Result<client::Client<S>> {
         client::Client::<S>::new_with_state(token, state).await
@@ -0,0 +1,72 @@
+//! Audit log related models
+
+use crate::models::{snowflake::Snowflake, user::User, webhook::Webhook};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The audit log of a guild, returned by [`HttpClient.get_audit_log`].
+/// [Discord Documentation](https://discord.com/developers/docs/resources/audit-log)
+///
+/// [`HttpClient.get_audit_log`]: ../../struct.HttpClient.html#method.get_audit_log
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuditLog {
+    /// The webhooks found in the audit log
+    pub webhooks: Vec<Webhook>,
+
+    /// The users found in the audit log
+    pub users: Vec<User>,
+
+    /// The entries of the audit log, the newest first
+    pub audit_log_entries: Vec<AuditLogEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuditLogEntry {
+    pub id: Snowflake,
+
+    /// The id of the affected entity (webhook, user, role, etc.)
+    pub target_id: Option<String>,
+
+    /// The changes made to the target
+    #[serde(default)]
+    pub changes: Vec<AuditLogChange>,
+
+    /// The user who made the changes
+    pub user_id: String,
+
+    /// The type of action that occurred.
+    /// [Discord Documentation](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events)
+    pub action_type: u16,
+
+    /// Additional info for certain action types
+    pub options: Option<AuditEntryInfo>,
+
+    /// The reason for the change
+    pub reason: Option<String>,
+}
+
+/// A change of an [`AuditLogEntry`], the values types depend on the key
+///
+/// [`AuditLogEntry`]: struct.AuditLogEntry.html
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuditLogChange {
+    pub key: String,
+    pub new_value: Option<Value>,
+    pub old_value: Option<Value>,
+}
+
+/// Additional info of an [`AuditLogEntry`], the fields that are sent depend on the action type
+///
+/// [`AuditLogEntry`]: struct.AuditLogEntry.html
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuditEntryInfo {
+    pub delete_member_days: Option<String>,
+    pub members_removed: Option<String>,
+    pub channel_id: Option<String>,
+    pub message_id: Option<String>,
+    pub count: Option<String>,
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub role_name: Option<String>,
+}
@@ -1,8 +1,9 @@
+use crate::models::snowflake::Snowflake;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Attachment {
-    pub id: String,
+    pub id: Snowflake,
     pub filename: String,
     pub size: u64,
     pub url: String,
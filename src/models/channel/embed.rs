@@ -1,74 +1,106 @@
 use serde::{Deserialize, Serialize};
 use std::default::Default;
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct Embed {
     /// Title of the embed
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<u64>, // TODO: COLOR ENUM
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub footer: Option<EmbedFooter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<EmbedImage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<EmbedThumbnail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video: Option<EmbedVideo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub provider: Option<EmbedProvider>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<EmbedAuthor>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<EmbedField>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct EmbedFooter {
-    text: String,
-    icon_url: Option<String>,
-    proxy_icon_url: Option<String>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_icon_url: Option<String>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct EmbedImage {
-    url: Option<String>,
-    proxy_url: Option<String>,
-    height: Option<u64>,
-    width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u64>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct EmbedThumbnail {
-    url: Option<String>,
-    proxy_url: Option<String>,
-    height: Option<u64>,
-    width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u64>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct EmbedVideo {
-    url: Option<String>,
-    height: Option<u64>,
-    width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u64>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct EmbedProvider {
-    name: Option<String>,
-    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct EmbedAuthor {
-    name: Option<String>,
-    url: Option<String>,
-    icon_url: Option<String>,
-    proxy_icon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_icon_url: Option<String>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct EmbedField {
-    name: String,
-    value: String,
-    inline: bool,
+    pub name: String,
+    pub value: String,
+    #[serde(default)]
+    pub inline: bool,
 }
 
 impl Embed {
@@ -94,8 +126,18 @@ impl Embed {
         self
     }
 
-    pub fn set_color(&mut self, _color: u64) -> &mut Self {
-        unimplemented!();
+    /// Set the color of the embed, as an integer RGB value, e.g. `0xFF0000`
+    pub fn set_color(&mut self, color: u64) -> &mut Self {
+        self.color = Some(color);
+
+        self
+    }
+
+    /// Set the timestamp of the embed, it needs to be in ISO8601 format
+    pub fn set_timestamp(&mut self, timestamp: impl Into<String>) -> &mut Self {
+        self.timestamp = Some(timestamp.into());
+
+        self
     }
 
     pub fn add_footer(&mut self, footer: EmbedFooter) -> &mut Self {
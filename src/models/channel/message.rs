@@ -1,20 +1,21 @@
 use crate::{
     error::Result,
     http::HttpClient,
-    models::{guild::GuildMember, user::User},
+    models::{guild::GuildMember, snowflake::Snowflake, user::User},
+    utils::builders::MessageEdit,
 };
 
-use super::{Embed, MentionChannel, MessageReference, Attachment, Reaction, MessageApplication};
+use super::{Embed, MentionChannel, MessageReference, Attachment, Reaction, MessageApplication, Sticker, StickerItem};
 
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 /// Represents a message sent in a channel within Discord.
 /// [Discord Documentation](https://discord.com/developers/docs/resources/channel#message-object)
 pub struct Message {
     /// ID of the message
-    pub id: String,
+    pub id: Snowflake,
     
     /// ID of the channel the message was sent in
     pub channel_id: String,
@@ -44,21 +45,24 @@ pub struct Message {
     pub mention_everyone: bool,
 
     /// Users specifically mentioned in the message
+    #[serde(default)]
     pub mentions: Vec<User>,
 
     /// Roles specifically mentioned in this message
+    #[serde(default)]
     pub mention_roles: Vec<String>,
     
     /// Channels specifically mentioned in this message
     #[serde(default)]
-    pub mentions_channels: Vec<MentionChannel>,
+    pub mention_channels: Vec<MentionChannel>,
 
     /// Any attached files
+    #[serde(default)]
     pub attachments: Vec<Attachment>,
 
     /// Any embedded content
     #[serde(default)]
-    pub embed: Vec<Embed>,
+    pub embeds: Vec<Embed>,
 
     /// Reactions to the message
     #[serde(default)]
@@ -82,14 +86,27 @@ pub struct Message {
     /// Sent with Rich Presence-related chat embeds
     pub application: Option<MessageApplication>,
 
-    /// Reference data sent with crossposted messages
+    /// Reference data sent with crossposted messages and replies
     pub message_reference: Option<MessageReference>,
 
+    /// The message this message replies to, it's `None` if the message isn't a reply, or if
+    /// the referenced message was deleted
+    #[serde(default)]
+    pub referenced_message: Option<Box<Message>>,
+
     /// Message flags ORd together, describes extra features of the message
     pub flags: Option<u64>,
+
+    /// Stickers sent with the message
+    #[serde(default)]
+    pub stickers: Vec<Sticker>,
+
+    /// The minimal data of the stickers sent with the message
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
 }
 
-#[derive(Debug, Serialize_repr, Deserialize_repr)]
+#[derive(Clone, Copy, Debug, Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 pub enum MessageKind {
     Regular = 0,
@@ -106,7 +123,8 @@ pub enum MessageKind {
     UserPremiumGuildSubT3 = 11,
     ChannelFollowAdd = 12,
     GuildDiscoveryDisqualified = 14,
-    GuildDiscoveryRequalified = 15
+    GuildDiscoveryRequalified = 15,
+    Reply = 19,
 }
 
 impl Message {
@@ -118,6 +136,13 @@ impl Message {
         http.send_message(&self.channel_id, content).await
     }
 
+    /// Shortcut for [`HttpClient.reply_to`], it replies to this message without pinging anyone
+    ///
+    /// [`HttpClient.reply_to`]: ../../../struct.HttpClient.html#method.reply_to
+    pub async fn reply(&self, http: &HttpClient, content: impl Into<String>) -> Result<Message> {
+        http.reply_to(&self.channel_id, self.id.as_str(), content, true).await
+    }
+
     /// Shortcut for [`HttpClient.send_embed`]
     ///
     /// [`HttpClient.send_embed`]: ../../../struct.HttpClient.html#method.send_embed
@@ -132,6 +157,14 @@ impl Message {
         http.add_reaction(&self.channel_id, &self.id, emoji).await
     }
 
+    /// Shortcut for [`HttpClient.edit_message`], it only edits the content of the message
+    ///
+    /// [`HttpClient.edit_message`]: ../../../struct.HttpClient.html#method.edit_message
+    pub async fn edit(&self, http: &HttpClient, content: impl Into<String>) -> Result<Message> {
+        http.edit_message(&self.channel_id, &self.id, MessageEdit::new().content(content))
+            .await
+    }
+
     /// Shortcut for [`HttpClient.delete_message`]
     ///
     /// [`HttpClient.delete_message`]: ../../../struct.HttpClient.html#method.delete_message
@@ -139,6 +172,13 @@ impl Message {
         http.delete_message(&self.channel_id, &self.id).await
     }
 
+    /// Shortcut for [`HttpClient.crosspost_message`]
+    ///
+    /// [`HttpClient.crosspost_message`]: ../../../struct.HttpClient.html#method.crosspost_message
+    pub async fn crosspost(&self, http: &HttpClient) -> Result<Message> {
+        http.crosspost_message(&self.channel_id, &self.id).await
+    }
+
     /// Shortcut for [`HttpClient.pin_message`]
     ///
     /// [`HttpClient.pin_message`]: ../../../struct.HttpClient.html#method.pin_message
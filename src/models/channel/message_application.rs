@@ -1,8 +1,9 @@
+use crate::models::snowflake::Snowflake;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageApplication {
-    pub id: String,
+    pub id: Snowflake,
     pub cover_image: Option<String>,
     pub description: String,
     pub icon: Option<String>,
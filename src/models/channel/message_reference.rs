@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+/// The source of a crossposted message, or the message replied by a reply
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReference {
-    pub id: Option<String>,
+    pub message_id: Option<String>,
     pub channel_id: Option<String>,
     pub guild_id: Option<String>,
 }
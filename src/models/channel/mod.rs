@@ -7,6 +7,7 @@ mod message_application;
 mod message_reference;
 mod overwrite;
 mod reaction;
+mod sticker;
 
 // Re-exports
 pub use attachment::Attachment;
@@ -14,17 +15,18 @@ pub use embed::{Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedPr
 pub use message::Message;
 pub use message_application::MessageApplication;
 pub use message_reference::MessageReference;
-pub use overwrite::Overwrite;
+pub use overwrite::{Overwrite, OverwriteType};
 pub use reaction::Reaction;
+pub use sticker::{Sticker, StickerFormat, StickerItem};
 
-use crate::models::user::*;
+use crate::models::{snowflake::Snowflake, user::*};
 
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Channel {
-    pub id: String,
+    pub id: Snowflake,
     #[serde(rename = "type")]
     pub kind: ChannelKind,
     pub guild_id: Option<String>,
@@ -51,6 +53,42 @@ pub struct Channel {
     pub last_pin_timestamp: Option<String>,
 }
 
+impl Channel {
+    /// Returns the type of the channel
+    pub fn kind(&self) -> ChannelKind {
+        self.kind
+    }
+
+    /// Returns true if messages can be sent in the channel, it's a guild text or news channel,
+    /// or a DM
+    pub fn is_text(&self) -> bool {
+        matches!(
+            self.kind,
+            ChannelKind::GuildText | ChannelKind::GuildNews | ChannelKind::DM | ChannelKind::GroupDM
+        )
+    }
+
+    /// Returns true if it's a guild voice channel
+    pub fn is_voice(&self) -> bool {
+        self.kind == ChannelKind::GuildVoice
+    }
+
+    /// Returns true if it's a DM or a group DM
+    pub fn is_dm(&self) -> bool {
+        self.kind == ChannelKind::DM || self.kind == ChannelKind::GroupDM
+    }
+
+    /// Returns true if it's a category, it contains other channels of the guild
+    pub fn is_category(&self) -> bool {
+        self.kind == ChannelKind::GuildCategory
+    }
+
+    /// Returns true if it's a channel of a guild
+    pub fn is_guild(&self) -> bool {
+        !self.is_dm()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MentionChannel {
     id: String,
@@ -60,7 +98,7 @@ pub struct MentionChannel {
     name: String,
 }
 
-#[derive(Clone, Debug, Deserialize_repr, Serialize_repr, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize_repr, Serialize_repr, PartialEq)]
 #[repr(u8)]
 pub enum ChannelKind {
     GuildText = 0,
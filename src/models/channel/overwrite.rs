@@ -1,10 +1,21 @@
+use crate::models::{guild::Permissions, snowflake::Snowflake};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Overwrite {
-    pub id: String,
+    pub id: Snowflake,
     #[serde(rename = "type")]
-    pub kind: String,
-    pub allow: u64,
-    pub deny: u64,
+    pub kind: OverwriteType,
+    pub allow: Permissions,
+    pub deny: Permissions,
+}
+
+/// The kind of target of a permission [`Overwrite`]
+///
+/// [`Overwrite`]: struct.Overwrite.html
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverwriteType {
+    Role,
+    Member,
 }
@@ -0,0 +1,32 @@
+use crate::models::snowflake::Snowflake;
+
+use serde::{Deserialize, Serialize};
+use serde_repr::*;
+
+/// A sticker sent in a message
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Sticker {
+    pub id: Snowflake,
+    pub pack_id: Option<String>,
+    pub name: String,
+    pub description: Option<String>,
+    /// Comma separated list of keywords
+    pub tags: Option<String>,
+    pub format_type: StickerFormat,
+}
+
+/// The minimal data of a sticker sent in a message
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StickerItem {
+    pub id: Snowflake,
+    pub name: String,
+    pub format_type: StickerFormat,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize_repr, Serialize_repr, PartialEq)]
+#[repr(u8)]
+pub enum StickerFormat {
+    Png = 1,
+    Apng = 2,
+    Lottie = 3,
+}
@@ -1,10 +1,11 @@
 use crate::models::guild::Role;
+use crate::models::snowflake::Snowflake;
 use crate::models::user::User;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Emoji {
-    pub id: Option<String>,
+    pub id: Option<Snowflake>,
     pub name: Option<String>,
     #[serde(default)]
     pub roles: Vec<Role>,
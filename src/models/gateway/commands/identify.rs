@@ -1,4 +1,7 @@
-use crate::models::gateway::payload::Opcode;
+use crate::models::{
+    gateway::{payload::Opcode, Intents},
+    user::StatusUpdate,
+};
 use serde::Serialize;
 
 #[derive(Debug, Serialize, PartialEq)]
@@ -15,8 +18,9 @@ pub(crate) struct IdentifyContent {
     pub(crate) compress: bool,
     pub(crate) large_threshold: Option<u8>,
     pub(crate) shard: Option<[u64; 2]>,
-    pub(crate) presence: Option<()>,
+    pub(crate) presence: Option<StatusUpdate>,
     pub(crate) guild_subscriptions: Option<bool>,
+    pub(crate) intents: Option<Intents>,
 }
 
 #[derive(Debug, Serialize, PartialEq)]
@@ -5,26 +5,31 @@ use identify::{Identify, IdentifyContent, IdentifyProperties};
 mod heartbeat;
 use heartbeat::Heartbeat;
 
+mod request_guild_members;
+use request_guild_members::{RequestGuildMembers, RequestGuildMembersContent};
+
 mod resume;
 use resume::{Resume, ResumeContent};
 
 mod status_update;
 use status_update::StatusUpdatePayload;
 
-use super::payload::{Opcode, Payload};
+mod voice_state_update;
+use voice_state_update::{VoiceStateUpdateContent, VoiceStateUpdatePayload};
+
+use super::{payload::Opcode, Intents};
 use crate::models::user;
 
 use async_tungstenite::tungstenite::Message as TungsteniteMessage;
 use std::env::consts::OS;
 
-#[allow(dead_code)] // TODO: Add support for Voice
 #[derive(Debug, PartialEq)]
 pub(crate) enum Command {
     Identify(Identify),
     Resume(Resume),
     Heartbeat(Heartbeat),
-    RequestGuildMembers(Payload),
-    UpdateVoiceState(Payload),
+    RequestGuildMembers(RequestGuildMembers),
+    UpdateVoiceState(VoiceStateUpdatePayload),
     StatusUpdate(StatusUpdatePayload),
     Close,
 }
@@ -52,6 +57,14 @@ impl Command {
                 let cmd_str = serde_json::to_string(&p).unwrap();
                 TungsteniteMessage::Text(cmd_str)
             }
+            Self::RequestGuildMembers(r) => {
+                let cmd_str = serde_json::to_string(&r).unwrap();
+                TungsteniteMessage::Text(cmd_str)
+            }
+            Self::UpdateVoiceState(v) => {
+                let cmd_str = serde_json::to_string(&v).unwrap();
+                TungsteniteMessage::Text(cmd_str)
+            }
             _ => todo!(),
         }
     }
@@ -62,6 +75,9 @@ impl Command {
         large_threshold: u8,
         guild_subscriptions: bool,
         shard: [u64; 2],
+        intents: Intents,
+        presence: Option<user::StatusUpdate>,
+        compress: bool,
     ) -> Command {
         let identify_properties = IdentifyProperties {
             os: OS,
@@ -74,11 +90,12 @@ impl Command {
             d: IdentifyContent {
                 token: token.into(),
                 properties: identify_properties,
-                compress: true,
+                compress,
                 large_threshold: Some(large_threshold),
                 shard: Some(shard),
-                presence: None,
+                presence,
                 guild_subscriptions: Some(guild_subscriptions),
+                intents: Some(intents),
             },
             s: None,
         };
@@ -114,4 +131,53 @@ impl Command {
 
         Command::StatusUpdate(status_update)
     }
+
+    /// Returns a RequestGuildMembers command, if neither `query` nor `user_ids` is given, all
+    /// the members are requested (`query` is sent empty)
+    pub(crate) fn new_request_guild_members(
+        guild_id: impl Into<String>,
+        query: Option<String>,
+        limit: u64,
+        presences: bool,
+        user_ids: Option<Vec<String>>,
+    ) -> Command {
+        // Discord requires one of them
+        let query = match (query, &user_ids) {
+            (None, None) => Some(String::new()),
+            (query, _) => query,
+        };
+
+        let request_guild_members = RequestGuildMembers {
+            op: Opcode::RequestGuildMember,
+            d: RequestGuildMembersContent {
+                guild_id: guild_id.into(),
+                query,
+                limit,
+                presences,
+                user_ids,
+            },
+        };
+
+        Command::RequestGuildMembers(request_guild_members)
+    }
+
+    /// Returns a UpdateVoiceState command, a None `channel_id` disconnects from the voice channel
+    pub(crate) fn new_voice_state_update(
+        guild_id: impl Into<String>,
+        channel_id: Option<String>,
+        self_mute: bool,
+        self_deaf: bool,
+    ) -> Command {
+        let voice_state_update = VoiceStateUpdatePayload {
+            op: Opcode::VoiceStateUpdate,
+            d: VoiceStateUpdateContent {
+                guild_id: guild_id.into(),
+                channel_id,
+                self_mute,
+                self_deaf,
+            },
+        };
+
+        Command::UpdateVoiceState(voice_state_update)
+    }
 }
@@ -0,0 +1,19 @@
+use crate::models::gateway::payload::Opcode;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct RequestGuildMembers {
+    pub(crate) op: Opcode,
+    pub(crate) d: RequestGuildMembersContent,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct RequestGuildMembersContent {
+    pub(crate) guild_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) query: Option<String>,
+    pub(crate) limit: u64,
+    pub(crate) presences: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) user_ids: Option<Vec<String>>,
+}
@@ -0,0 +1,16 @@
+use crate::models::gateway::payload::Opcode;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct VoiceStateUpdatePayload {
+    pub(crate) op: Opcode,
+    pub(crate) d: VoiceStateUpdateContent,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub(crate) struct VoiceStateUpdateContent {
+    pub(crate) guild_id: String,
+    pub(crate) channel_id: Option<String>,
+    pub(crate) self_mute: bool,
+    pub(crate) self_deaf: bool,
+}
@@ -1,7 +1,8 @@
+use crate::models::snowflake::Snowflake;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildDelete {
-    pub id: String,
+    pub id: Snowflake,
     pub unavailable: bool,
 }
@@ -1,10 +1,12 @@
 use crate::models::user::User;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildMemberUpdate {
     pub guild_id: String,
     pub roles: Vec<String>,
     pub user: User,
     pub nick: String,
+    #[serde(default)]
+    pub communication_disabled_until: Option<String>,
 }
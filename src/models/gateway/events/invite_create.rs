@@ -0,0 +1,37 @@
+use crate::models::user::User;
+use serde::{Deserialize, Serialize};
+
+/// Sent when a new invite to a channel is created
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InviteCreate {
+    /// The channel the invite is for
+    pub channel_id: String,
+
+    /// The unique invite code
+    pub code: String,
+
+    /// The time at which the invite was created
+    pub created_at: String,
+
+    /// The guild of the invite
+    pub guild_id: Option<String>,
+
+    /// The user that created the invite
+    pub inviter: Option<User>,
+
+    /// How long the invite is valid for, in seconds
+    pub max_age: u64,
+
+    /// The maximum number of times the invite can be used
+    pub max_uses: u64,
+
+    /// The target user for this invite
+    pub target_user: Option<User>,
+
+    /// Whether or not the invite is temporary, invited users will be kicked on disconnect unless
+    /// they're assigned a role
+    pub temporary: bool,
+
+    /// How many times the invite has been used, always 0
+    pub uses: u64,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Sent when an invite is deleted
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InviteDelete {
+    /// The channel of the invite
+    pub channel_id: String,
+
+    /// The guild of the invite
+    pub guild_id: Option<String>,
+
+    /// The unique invite code
+    pub code: String,
+}
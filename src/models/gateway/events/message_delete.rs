@@ -1,8 +1,9 @@
+use crate::models::snowflake::Snowflake;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageDelete {
-    pub id: String,
+    pub id: Snowflake,
     pub channel_id: String,
     pub guild_id: Option<String>,
 }
@@ -1,7 +1,7 @@
 use crate::models::{emoji::Emoji, guild::GuildMember};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReactionAdd {
     pub user_id: String,    //
     pub channel_id: String, //
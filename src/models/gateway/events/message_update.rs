@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use std::ops::Deref;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageUpdate(pub Message);
 
 impl Deref for MessageUpdate {
@@ -19,6 +19,10 @@ mod guild_role_delete;
 mod guild_role_update;
 mod guild_update;
 
+// Invite
+mod invite_create;
+mod invite_delete;
+
 // Message
 mod message_create;
 mod message_delete;
@@ -61,6 +65,10 @@ pub use guild_role_delete::GuildRoleDelete;
 pub use guild_role_update::GuildRoleUpdate;
 pub use guild_update::GuildUpdate;
 
+// INVITE
+pub use invite_create::InviteCreate;
+pub use invite_delete::InviteDelete;
+
 // MESSAGE
 pub use message_create::MessageCreate;
 pub use message_delete::MessageDelete;
@@ -100,11 +108,23 @@ pub(crate) enum Event {
     InvalidSession(bool),    // op: 9
     Hello(u64),              // op: 10
     HeartbeatACK,            // op: 11
-    Close(PandaError),
+    Raw(Value),
+    Close(PandaError, Option<CloseFrame>),
 }
 
+/// The code and the reason of the close frame sent by Discord, the codes are listed in
+/// https://discord.com/developers/docs/topics/opcodes-and-status-codes#gateway-gateway-close-event-codes
 #[derive(Debug)]
-pub(crate) enum DispatchEvent {
+pub(crate) struct CloseFrame {
+    pub(crate) code: u16,
+    pub(crate) reason: String,
+}
+
+/// The events dispatched by the gateway, they are received with [`Client.events`]
+///
+/// [`Client.events`]: ../client/struct.Client.html#method.events
+#[derive(Clone, Debug)]
+pub enum DispatchEvent {
     Ready(Ready),
     Resumed,
     Reconnect,
@@ -129,6 +149,10 @@ pub(crate) enum DispatchEvent {
     GuildRoleUpdate(GuildRoleUpdate),
     GuildRoleDelete(GuildRoleDelete),
 
+    // invite
+    InviteCreate(InviteCreate),
+    InviteDelete(InviteDelete),
+
     // message
     MessageCreate(MessageCreate),
     MessageUpdate(MessageUpdate),
@@ -147,6 +171,12 @@ pub(crate) enum DispatchEvent {
     // voice
     VoiceStateUpdate(VoiceStateUpdate),
     VoiceServerUpdate(VoiceServerUpdate),
+
+    /// An event that panda doesn't know yet, with its name and its raw data
+    Unknown {
+        name: String,
+        raw: Value,
+    },
 }
 
 impl TryFrom<Payload> for Event {
@@ -252,7 +282,7 @@ fn handle_dispatch(p: Payload) -> Result<DispatchEvent> {
             let event = serde_json::from_value(d)?;
             Ok(DispatchEvent::GuildMemberRemove(event))
         }
-        "GUILD_MEMBER_CHUNK" => {
+        "GUILD_MEMBERS_CHUNK" => {
             let event = serde_json::from_value(d)?;
             Ok(DispatchEvent::GuildMembersChunk(event))
         }
@@ -269,6 +299,16 @@ fn handle_dispatch(p: Payload) -> Result<DispatchEvent> {
             Ok(DispatchEvent::GuildRoleDelete(event))
         }
 
+        // Invite
+        "INVITE_CREATE" => {
+            let event = serde_json::from_value(d)?;
+            Ok(DispatchEvent::InviteCreate(event))
+        }
+        "INVITE_DELETE" => {
+            let event = serde_json::from_value(d)?;
+            Ok(DispatchEvent::InviteDelete(event))
+        }
+
         // Message
         "MESSAGE_CREATE" => {
             let event = serde_json::from_value(d)?;
@@ -326,6 +366,33 @@ fn handle_dispatch(p: Payload) -> Result<DispatchEvent> {
             let event = serde_json::from_value(d)?;
             Ok(DispatchEvent::VoiceServerUpdate(event))
         }
-        _ => Err(PandaError::InvalidPayloadFormat),
+
+        // New events are received as Unknown, so the connection keeps working
+        _ => Ok(DispatchEvent::Unknown { name: t, raw: d }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn unknown_events_keep_name_and_data() {
+        let payload: Payload = serde_json::from_value(json!({
+            "op": 0,
+            "s": 42,
+            "t": "SOME_NEW_EVENT",
+            "d": { "id": "1", "nested": [true, null] },
+        }))
+        .unwrap();
+
+        match handle_dispatch(payload).unwrap() {
+            DispatchEvent::Unknown { name, raw } => {
+                assert_eq!(name, "SOME_NEW_EVENT");
+                assert_eq!(raw, json!({ "id": "1", "nested": [true, null] }));
+            }
+            event => panic!("expected an unknown event, got {:?}", event),
+        }
     }
 }
@@ -1,30 +1,19 @@
 use crate::models::user::*;
 
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PresenceUpdate {
-    #[serde(rename = "user")]
-    #[serde(deserialize_with = "deserialize_user_id")]
-    pub user_id: String,
+    /// The user of the presence, Discord doesn't always send the full user, but the id is
+    /// always sent
+    pub user: PartialUser,
+    #[serde(default)]
     pub roles: Vec<String>,
     pub game: Option<Activity>,
     pub guild_id: String,
-    pub status: String, // use enum
+    pub status: Status,
+    #[serde(default)]
     pub activities: Vec<Activity>,
-    //client_status: ClientStatus
-}
-
-// We use this function because discord not always send a full user,
-// but always send the user id.
-fn deserialize_user_id<'de, D>(deserializer: D) -> Result<String, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    #[derive(Deserialize)]
-    struct User {
-        id: String,
-    }
-
-    Deserialize::deserialize(deserializer).map(|user: User| user.id)
+    #[serde(default)]
+    pub client_status: ClientStatus,
 }
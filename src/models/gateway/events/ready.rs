@@ -3,14 +3,24 @@ use crate::models::user::*;
 
 use serde::{Deserialize, Serialize};
 
+/// The first event received after the IDENTIFY, it has the initial state of the session
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Ready {
+    /// Gateway version
     pub v: u8,
+
+    /// The bot user
     pub user: User,
+
     // pub private_channels: Option<Vec<()>>,
-    #[serde(skip_deserializing)]
-    pub guilds: Vec<Guild>,
+    /// The guilds of the bot, they are unavailable until their [`GuildCreate`] event is received
+    ///
+    /// [`GuildCreate`]: struct.GuildCreate.html
+    pub guilds: Vec<UnavailableGuild>,
 
+    /// Used to resume the session
     pub session_id: String,
+
+    /// The shard id and the number of shards of this session
     pub shard: Option<[u64; 2]>,
 }
@@ -1,8 +1,15 @@
-use serde::{ Deserialize, Serialize };
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+/// Sent when the voice server of a guild is assigned or changes, it has the information needed
+/// to connect to the voice server
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct VoiceServerUpdate {
-    token: String,
-    guild_id: String,
-    endpoint: String
-}
\ No newline at end of file
+    /// Voice connection token
+    pub token: String,
+
+    /// The guild this voice server update is for
+    pub guild_id: String,
+
+    /// The voice server host, it's None when the voice server is unavailable
+    pub endpoint: Option<String>,
+}
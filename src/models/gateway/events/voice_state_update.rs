@@ -1,5 +1,17 @@
 use crate::models::voice::*;
-use serde::{ Deserialize, Serialize };
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct VoiceStateUpdate(pub VoiceState);
\ No newline at end of file
+use std::ops::Deref;
+
+/// Sent when someone joins, leaves or moves between voice channels, `channel_id` is None when
+/// the user disconnects
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct VoiceStateUpdate(pub VoiceState);
+
+impl Deref for VoiceStateUpdate {
+    type Target = VoiceState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// The response of GET /gateway/bot, it's returned by [`HttpClient.get_gateway_bot`]
+///
+/// [`HttpClient.get_gateway_bot`]: ../../struct.HttpClient.html#method.get_gateway_bot
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GatewayBot {
+    /// The WSS URL that can be used for connecting to the gateway
+    pub url: String,
+
+    /// The recommended number of shards to use when connecting
+    pub shards: u64,
+
+    /// Information on the current session start limit
+    pub session_start_limit: SessionStartLimit,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SessionStartLimit {
+    /// The total number of session starts the current user is allowed
+    pub total: u64,
+
+    /// The remaining number of session starts the current user is allowed
+    pub remaining: u64,
+
+    /// The number of milliseconds after which the limit resets
+    pub reset_after: u64,
+
+    /// The number of IDENTIFY requests allowed per 5 seconds
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: u64,
+}
+
+fn default_max_concurrency() -> u64 {
+    1
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use std::ops::{BitAnd, BitOr, BitOrAssign, Not};
+
+/// Gateway intents, used to choose which events the gateway will send to the client.
+/// [Discord Documentation](https://discord.com/developers/docs/topics/gateway#gateway-intents)
+///
+/// `GUILD_MEMBERS`, `GUILD_PRESENCES` and `MESSAGE_CONTENT` are privileged intents, they need
+/// to be enabled in the developer portal before they can be used.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Intents(u64);
+
+impl Intents {
+    pub const GUILDS: Intents = Intents(1 << 0);
+    pub const GUILD_MEMBERS: Intents = Intents(1 << 1);
+    pub const GUILD_BANS: Intents = Intents(1 << 2);
+    pub const GUILD_EMOJIS: Intents = Intents(1 << 3);
+    pub const GUILD_INTEGRATIONS: Intents = Intents(1 << 4);
+    pub const GUILD_WEBHOOKS: Intents = Intents(1 << 5);
+    pub const GUILD_INVITES: Intents = Intents(1 << 6);
+    pub const GUILD_VOICE_STATES: Intents = Intents(1 << 7);
+    pub const GUILD_PRESENCES: Intents = Intents(1 << 8);
+    pub const GUILD_MESSAGES: Intents = Intents(1 << 9);
+    pub const GUILD_MESSAGE_REACTIONS: Intents = Intents(1 << 10);
+    pub const GUILD_MESSAGE_TYPING: Intents = Intents(1 << 11);
+    pub const DIRECT_MESSAGES: Intents = Intents(1 << 12);
+    pub const DIRECT_MESSAGE_REACTIONS: Intents = Intents(1 << 13);
+    pub const DIRECT_MESSAGE_TYPING: Intents = Intents(1 << 14);
+    /// Without it the content, embeds and attachments of the messages are empty, except in
+    /// DMs and in the messages that mention the bot
+    pub const MESSAGE_CONTENT: Intents = Intents(1 << 15);
+
+    /// Returns an empty set of intents
+    pub const fn empty() -> Intents {
+        Intents(0)
+    }
+
+    /// Returns all intents, including the privileged ones
+    pub const fn all() -> Intents {
+        Intents((1 << 16) - 1)
+    }
+
+    /// Returns all the intents that are not privileged, it's the default value of [`Config`]
+    ///
+    /// [`Config`]: ../../client/struct.Config.html
+    pub const fn non_privileged() -> Intents {
+        Intents(Self::all().0 & !(Self::GUILD_MEMBERS.0 | Self::GUILD_PRESENCES.0 | Self::MESSAGE_CONTENT.0))
+    }
+
+    /// Creates intents from a raw value
+    pub const fn from_bits(bits: u64) -> Intents {
+        Intents(bits)
+    }
+
+    /// Returns the raw value of the intents
+    pub const fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns true if all intents of `other` are contained in `self`
+    pub const fn contains(&self, other: Intents) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Adds the given intents
+    pub fn insert(&mut self, other: Intents) {
+        self.0 |= other.0;
+    }
+
+    /// Removes the given intents
+    pub fn remove(&mut self, other: Intents) {
+        self.0 &= !other.0;
+    }
+}
+
+impl Default for Intents {
+    fn default() -> Self {
+        Intents::non_privileged()
+    }
+}
+
+impl BitOr for Intents {
+    type Output = Intents;
+
+    fn bitor(self, rhs: Intents) -> Intents {
+        Intents(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Intents {
+    fn bitor_assign(&mut self, rhs: Intents) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for Intents {
+    type Output = Intents;
+
+    fn bitand(self, rhs: Intents) -> Intents {
+        Intents(self.0 & rhs.0)
+    }
+}
+
+impl Not for Intents {
+    type Output = Intents;
+
+    fn not(self) -> Intents {
+        Intents(!self.0 & Self::all().0)
+    }
+}
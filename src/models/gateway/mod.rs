@@ -5,3 +5,8 @@ pub(crate) mod commands;
 pub(crate) mod payload;
 
 pub mod events;
+mod gateway_bot;
+mod intents;
+
+pub use gateway_bot::{GatewayBot, SessionStartLimit};
+pub use intents::Intents;
@@ -33,6 +33,7 @@ pub(crate) enum Opcode {
     Heartbeat = 1,
     Identify = 2,
     StatusUpdate = 3,
+    VoiceStateUpdate = 4,
     Resume = 6,
     Reconnect = 7,
     RequestGuildMember = 8,
@@ -76,16 +77,16 @@ impl TryFrom<TungsteniteMessage> for Payload {
                     // 4003 => this shouldn't happen
                     4004 => return Err(PandaError::AuthenticationFailed),
                     // 4005 => this shouldn't happen
-                    4007 => {
-                        log::error!("Panda error: Invalid seq sended");
-                        return Err(PandaError::ConnectionClosed);
-                    }
-                    4008 => return Err(PandaError::ConnectionClosed), // TODO: Improve this
-                    4009 => return Err(PandaError::ConnectionClosed), // TODO: Improve this
+                    4007 => return Err(PandaError::InvalidSequence),
+                    4008 => return Err(PandaError::RateLimited),
+                    4009 => return Err(PandaError::SessionTimeout),
                     4010 => return Err(PandaError::InvalidShard),
                     4011 => return Err(PandaError::ShardingRequired),
                     4012 => return Err(PandaError::InvalidApiGatewayVersion),
-                    _ => panic!("UNDEFINED Close error received"),
+                    4013 => return Err(PandaError::InvalidIntents),
+                    4014 => return Err(PandaError::DisallowedIntents),
+                    // The client logs the code and the reason of the close frame
+                    _ => return Err(PandaError::ConnectionClosed),
                 }
             }
             _ => todo!(),
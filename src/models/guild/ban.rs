@@ -0,0 +1,15 @@
+use crate::models::user::User;
+use serde::{Deserialize, Serialize};
+
+/// A ban of a guild, returned by [`HttpClient.get_guild_bans`] and [`HttpClient.get_guild_ban`]
+///
+/// [`HttpClient.get_guild_bans`]: ../../struct.HttpClient.html#method.get_guild_bans
+/// [`HttpClient.get_guild_ban`]: ../../struct.HttpClient.html#method.get_guild_ban
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Ban {
+    /// The reason for the ban
+    pub reason: Option<String>,
+
+    /// The banned user
+    pub user: User,
+}
@@ -11,6 +11,10 @@ pub struct Member {
     pub premium_since: Option<String>,
     pub deaf: bool,
     pub mute: bool,
+    /// ISO8601 timestamp of when the timeout of the member ends, it's None if the member
+    /// isn't timed out
+    #[serde(default)]
+    pub communication_disabled_until: Option<String>,
 
     // only for GUILD_MEMBER_ADD
     pub guild_id: Option<String>,
@@ -1,21 +1,25 @@
+mod ban;
 mod member;
+mod permissions;
 mod role;
 
-use crate::models::{channel::Channel, emoji::Emoji};
+use crate::models::{channel::Channel, emoji::Emoji, snowflake::Snowflake};
 use serde::{Deserialize, Serialize};
 
+pub use ban::Ban;
 pub use member::Member as GuildMember;
+pub use permissions::Permissions;
 pub use role::Role;
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Guild {
-    pub id: String,
+    pub id: Snowflake,
     pub name: String,
     pub icon: Option<String>,
     pub splash: Option<String>,
     pub owner: Option<bool>,
     pub owner_id: String,
-    pub permissions: Option<u64>,
+    pub permissions: Option<Permissions>,
     pub region: String,
     pub afk_channel_id: Option<String>,
     pub afk_timeout: u64,
@@ -40,13 +44,44 @@ pub struct Guild {
     pub premium_tier: u64,
     pub premium_subscription_count: Option<u64>,
     pub preferred_locale: String,
-    // elds are only sent within GUILD_CREATE event
+    // fields are only sent within GUILD_CREATE event
+    #[serde(default)]
     pub joined_at: String,
     pub large: Option<bool>,
+    #[serde(default)]
     pub unavailable: bool,
     pub member_count: Option<u64>,
     // ce_states: Vec<Voice>,
+    #[serde(default)]
     pub members: Vec<GuildMember>,
+    #[serde(default)]
     pub channels: Vec<Channel>,
     // pub presences: Vec<Presence>,
 }
+
+/// A guild that isn't available yet, they are sent in the [`Ready`] event, and the full guild
+/// is received later in a [`GuildCreate`] event
+///
+/// [`Ready`]: ../gateway/events/struct.Ready.html
+/// [`GuildCreate`]: ../gateway/events/struct.GuildCreate.html
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UnavailableGuild {
+    pub id: Snowflake,
+    #[serde(default)]
+    pub unavailable: bool,
+}
+
+/// A guild with only the basic fields, they are returned by [`HttpClient.get_current_user_guilds`]
+///
+/// [`HttpClient.get_current_user_guilds`]: ../../struct.HttpClient.html#method.get_current_user_guilds
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PartialGuild {
+    pub id: Snowflake,
+    pub name: String,
+    pub icon: Option<String>,
+    /// True if the bot is the owner of the guild
+    #[serde(default)]
+    pub owner: bool,
+    /// The permissions of the bot in the guild
+    pub permissions: Permissions,
+}
@@ -0,0 +1,156 @@
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr, BitOrAssign, Not},
+};
+
+/// Permissions of roles, members and channel overwrites.
+/// [Discord Documentation](https://discord.com/developers/docs/topics/permissions)
+///
+/// Discord sends them as integers or as strings, both can be deserialized, and they are
+/// serialized as strings.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Permissions(u64);
+
+impl Permissions {
+    pub const CREATE_INSTANT_INVITE: Permissions = Permissions(1 << 0);
+    pub const KICK_MEMBERS: Permissions = Permissions(1 << 1);
+    pub const BAN_MEMBERS: Permissions = Permissions(1 << 2);
+    pub const ADMINISTRATOR: Permissions = Permissions(1 << 3);
+    pub const MANAGE_CHANNELS: Permissions = Permissions(1 << 4);
+    pub const MANAGE_GUILD: Permissions = Permissions(1 << 5);
+    pub const ADD_REACTIONS: Permissions = Permissions(1 << 6);
+    pub const VIEW_AUDIT_LOG: Permissions = Permissions(1 << 7);
+    pub const PRIORITY_SPEAKER: Permissions = Permissions(1 << 8);
+    pub const STREAM: Permissions = Permissions(1 << 9);
+    pub const VIEW_CHANNEL: Permissions = Permissions(1 << 10);
+    pub const SEND_MESSAGES: Permissions = Permissions(1 << 11);
+    pub const SEND_TTS_MESSAGES: Permissions = Permissions(1 << 12);
+    pub const MANAGE_MESSAGES: Permissions = Permissions(1 << 13);
+    pub const EMBED_LINKS: Permissions = Permissions(1 << 14);
+    pub const ATTACH_FILES: Permissions = Permissions(1 << 15);
+    pub const READ_MESSAGE_HISTORY: Permissions = Permissions(1 << 16);
+    pub const MENTION_EVERYONE: Permissions = Permissions(1 << 17);
+    pub const USE_EXTERNAL_EMOJIS: Permissions = Permissions(1 << 18);
+    pub const VIEW_GUILD_INSIGHTS: Permissions = Permissions(1 << 19);
+    pub const CONNECT: Permissions = Permissions(1 << 20);
+    pub const SPEAK: Permissions = Permissions(1 << 21);
+    pub const MUTE_MEMBERS: Permissions = Permissions(1 << 22);
+    pub const DEAFEN_MEMBERS: Permissions = Permissions(1 << 23);
+    pub const MOVE_MEMBERS: Permissions = Permissions(1 << 24);
+    pub const USE_VAD: Permissions = Permissions(1 << 25);
+    pub const CHANGE_NICKNAME: Permissions = Permissions(1 << 26);
+    pub const MANAGE_NICKNAMES: Permissions = Permissions(1 << 27);
+    pub const MANAGE_ROLES: Permissions = Permissions(1 << 28);
+    pub const MANAGE_WEBHOOKS: Permissions = Permissions(1 << 29);
+    pub const MANAGE_EMOJIS: Permissions = Permissions(1 << 30);
+    pub const MODERATE_MEMBERS: Permissions = Permissions(1 << 40);
+
+    /// Returns an empty set of permissions
+    pub const fn empty() -> Permissions {
+        Permissions(0)
+    }
+
+    /// Returns all the permissions
+    pub const fn all() -> Permissions {
+        Permissions(((1 << 31) - 1) | Self::MODERATE_MEMBERS.0)
+    }
+
+    /// Creates permissions from a raw value
+    pub const fn from_bits(bits: u64) -> Permissions {
+        Permissions(bits)
+    }
+
+    /// Returns the raw value of the permissions
+    pub const fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns true if all permissions of `other` are contained in `self`
+    pub const fn contains(&self, other: Permissions) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if there are no permissions
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Adds the given permissions
+    pub fn insert(&mut self, other: Permissions) {
+        self.0 |= other.0;
+    }
+
+    /// Removes the given permissions
+    pub fn remove(&mut self, other: Permissions) {
+        self.0 &= !other.0;
+    }
+}
+
+impl BitOr for Permissions {
+    type Output = Permissions;
+
+    fn bitor(self, rhs: Permissions) -> Permissions {
+        Permissions(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Permissions {
+    fn bitor_assign(&mut self, rhs: Permissions) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for Permissions {
+    type Output = Permissions;
+
+    fn bitand(self, rhs: Permissions) -> Permissions {
+        Permissions(self.0 & rhs.0)
+    }
+}
+
+impl Not for Permissions {
+    type Output = Permissions;
+
+    fn not(self) -> Permissions {
+        Permissions(!self.0 & Self::all().0)
+    }
+}
+
+impl Serialize for Permissions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Permissions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(PermissionsVisitor)
+    }
+}
+
+struct PermissionsVisitor;
+
+impl<'de> Visitor<'de> for PermissionsVisitor {
+    type Value = Permissions;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an integer or a string with an integer")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Permissions, E> {
+        Ok(Permissions(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Permissions, E> {
+        Ok(Permissions(value as u64))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Permissions, E> {
+        value.parse().map(Permissions).map_err(E::custom)
+    }
+}
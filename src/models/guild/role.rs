@@ -1,15 +1,17 @@
 //! Guild related models
 
+use super::Permissions;
+use crate::models::snowflake::Snowflake;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Role {
-    pub id: String,
+    pub id: Snowflake,
     pub name: String,
     pub color: u64, // maybe create type
     pub hoist: bool,
     pub position: u64,
-    pub permissions: u64,
+    pub permissions: Permissions,
     pub managed: bool,
     pub mentionable: bool,
 }
@@ -0,0 +1,37 @@
+//! Invite related models
+
+use crate::models::{channel::Channel, snowflake::Snowflake, user::User};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Invite {
+    /// The invite code, it's used in `https://discord.gg/{code}`
+    pub code: String,
+    pub guild: Option<InviteGuild>,
+    /// Partial channel, only with the id, name and kind
+    pub channel: Channel,
+    pub inviter: Option<User>,
+    pub target_user: Option<User>,
+    pub approximate_presence_count: Option<u64>,
+    pub approximate_member_count: Option<u64>,
+
+    // Metadata, only sent when the invite is created
+    pub uses: Option<u64>,
+    pub max_uses: Option<u64>,
+    pub max_age: Option<u64>,
+    pub temporary: Option<bool>,
+    pub created_at: Option<String>,
+}
+
+/// The partial guild sent in the invites
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InviteGuild {
+    pub id: Snowflake,
+    pub name: String,
+    pub icon: Option<String>,
+    pub splash: Option<String>,
+    pub banner: Option<String>,
+    pub description: Option<String>,
+    pub verification_level: Option<u64>,
+    pub vanity_url_code: Option<String>,
+}
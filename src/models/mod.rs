@@ -2,6 +2,8 @@
 //!
 //! Here you can find all models from the Discord API
 
+#[doc(inline)]
+pub mod audit_log;
 #[doc(inline)]
 pub mod channel;
 #[doc(inline)]
@@ -11,14 +13,24 @@ pub mod gateway;
 #[doc(inline)]
 pub mod guild;
 #[doc(inline)]
+pub mod invite;
+#[doc(inline)]
+pub mod snowflake;
+#[doc(inline)]
 pub mod user;
 #[doc(inline)]
 pub mod voice;
+#[doc(inline)]
+pub mod webhook;
 
 // Re-export all models
+pub use audit_log::*;
 pub use channel::*;
 pub use emoji::*;
 pub use gateway::*;
 pub use guild::*;
+pub use invite::*;
+pub use snowflake::*;
 pub use user::*;
 pub use voice::*;
+pub use webhook::*;
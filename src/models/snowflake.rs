@@ -0,0 +1,201 @@
+//! Discord ids
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+/// The first second of 2015, the snowflake timestamps are relative to it
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// An unique Discord id, the creation time of the object is encoded in it.
+/// [Discord Documentation](https://discord.com/developers/docs/reference#snowflakes)
+///
+/// Discord sends them as strings, they are kept as strings so a Snowflake can be used in all
+/// the methods that take an `impl AsRef<str>` id, the integer is kept next to it.
+#[derive(Clone, Debug)]
+pub struct Snowflake {
+    id: u64,
+    string: String,
+}
+
+impl Snowflake {
+    /// Returns the id as an integer
+    pub fn as_u64(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the creation time of the object, in milliseconds since the Unix epoch
+    pub fn timestamp(&self) -> u64 {
+        (self.as_u64() >> 22) + DISCORD_EPOCH
+    }
+
+    /// Returns the id of the internal worker that generated the id
+    pub fn worker_id(&self) -> u8 {
+        ((self.as_u64() & 0x3E_0000) >> 17) as u8
+    }
+
+    /// Returns the id of the internal process that generated the id
+    pub fn process_id(&self) -> u8 {
+        ((self.as_u64() & 0x1_F000) >> 12) as u8
+    }
+
+    /// Returns the increment of the id, it's incremented for every id generated on the process
+    pub fn increment(&self) -> u16 {
+        (self.as_u64() & 0xFFF) as u16
+    }
+
+    /// Returns the id as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(id: u64) -> Snowflake {
+        Snowflake {
+            id,
+            string: id.to_string(),
+        }
+    }
+}
+
+impl FromStr for Snowflake {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Snowflake, ParseIntError> {
+        s.parse::<u64>().map(Snowflake::from)
+    }
+}
+
+impl fmt::Display for Snowflake {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.string)
+    }
+}
+
+impl AsRef<str> for Snowflake {
+    fn as_ref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl Borrow<str> for Snowflake {
+    fn borrow(&self) -> &str {
+        &self.string
+    }
+}
+
+impl PartialEq for Snowflake {
+    fn eq(&self, other: &Snowflake) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Snowflake {}
+
+// The hash must be the same as the hash of the string, maps of snowflakes are searched with &str
+impl Hash for Snowflake {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.string.hash(state);
+    }
+}
+
+impl PartialEq<str> for Snowflake {
+    fn eq(&self, other: &str) -> bool {
+        self.string == other
+    }
+}
+
+impl PartialEq<String> for Snowflake {
+    fn eq(&self, other: &String) -> bool {
+        &self.string == other
+    }
+}
+
+impl PartialEq<Snowflake> for String {
+    fn eq(&self, other: &Snowflake) -> bool {
+        self == &other.string
+    }
+}
+
+impl Ord for Snowflake {
+    fn cmp(&self, other: &Snowflake) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl PartialOrd for Snowflake {
+    fn partial_cmp(&self, other: &Snowflake) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Serialize for Snowflake {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.string)
+    }
+}
+
+impl<'de> Deserialize<'de> for Snowflake {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SnowflakeVisitor)
+    }
+}
+
+struct SnowflakeVisitor;
+
+impl<'de> Visitor<'de> for SnowflakeVisitor {
+    type Value = Snowflake;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string with an integer or an integer")
+    }
+
+    // Small ids can be decoded as integers with the ETF encoding
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Snowflake, E> {
+        Ok(Snowflake::from(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Snowflake, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example of the Discord documentation
+    const ID: u64 = 175928847299117063;
+
+    #[test]
+    fn parts_of_the_id() {
+        let snowflake: Snowflake = "175928847299117063".parse().unwrap();
+
+        assert_eq!(snowflake.as_u64(), ID);
+        assert_eq!(snowflake.timestamp(), 1462015105796);
+        assert_eq!(snowflake.worker_id(), 1);
+        assert_eq!(snowflake.process_id(), 0);
+        assert_eq!(snowflake.increment(), 7);
+    }
+
+    #[test]
+    fn string_and_integer_are_the_same_id() {
+        let from_str: Snowflake = serde_json::from_str("\"175928847299117063\"").unwrap();
+        let from_u64: Snowflake = serde_json::from_str("175928847299117063").unwrap();
+
+        assert_eq!(from_str, from_u64);
+        assert_eq!(from_u64.as_str(), "175928847299117063");
+        assert_eq!(serde_json::to_string(&from_u64).unwrap(), "\"175928847299117063\"");
+    }
+}
@@ -4,7 +4,7 @@ use serde_repr::*;
 
 use std::default::Default;
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct Activity {
     pub name: String,
     #[serde(rename = "type")]
@@ -15,19 +15,28 @@ pub struct Activity {
     pub details: Option<String>,
     pub state: Option<String>,
     pub emoji: Option<Emoji>,
-    pub party: Option<String>,
+    pub party: Option<ActivityParty>,
     // assets: Assets,
     // secrets: Secrets,
     pub instance: Option<bool>,
     pub flags: Option<u64>,
 }
 
-#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq)]
+/// The party of the activity
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ActivityParty {
+    pub id: Option<String>,
+    /// The current and the max size of the party
+    pub size: Option<[u64; 2]>,
+}
+
+#[derive(Clone, Debug, Deserialize_repr, Serialize_repr, PartialEq)]
 #[repr(u8)]
 pub enum ActivityKind {
     Game = 0,
     Streaming = 1,
     Listening = 2,
+    Watching = 3,
     Custom = 4,
 }
 
@@ -0,0 +1,15 @@
+use super::Status;
+use serde::{Deserialize, Serialize};
+
+/// The status of the user in each platform, it's None if the user isn't active on it
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct ClientStatus {
+    /// Status in the desktop application
+    pub desktop: Option<Status>,
+
+    /// Status in the mobile application
+    pub mobile: Option<Status>,
+
+    /// Status in the web application, or in a bot
+    pub web: Option<Status>,
+}
@@ -1,15 +1,19 @@
 //! User related models
 
 mod activity;
+mod client_status;
 mod status_update;
 
-pub use activity::{Activity, ActivityKind};
+pub use activity::{Activity, ActivityKind, ActivityParty};
+pub use client_status::ClientStatus;
 pub use status_update::{Status, StatusUpdate};
 
+use crate::models::snowflake::Snowflake;
 use serde::{Deserialize, Serialize};
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct User {
-    pub id: String,
+    pub id: Snowflake,
     pub username: String,
     pub discriminator: String,
     pub avatar: Option<String>,
@@ -21,3 +25,47 @@ pub struct User {
     pub flags: Option<u64>,
     pub premium_type: Option<u64>,
 }
+
+/// A user that can be sent without all its fields, like the user of a [`PresenceUpdate`], only
+/// the id is always sent
+///
+/// [`PresenceUpdate`]: ../gateway/events/struct.PresenceUpdate.html
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PartialUser {
+    pub id: Snowflake,
+    pub username: Option<String>,
+    pub discriminator: Option<String>,
+    pub avatar: Option<String>,
+    pub bot: Option<bool>,
+}
+
+/// Base URL of the images of Discord
+const CDN_URL: &str = "https://cdn.discordapp.com";
+
+impl User {
+    /// Returns the tag of the user, `username#discriminator`
+    pub fn tag(&self) -> String {
+        format!("{}#{}", self.username, self.discriminator)
+    }
+
+    /// Returns the mention of the user, `<@id>`
+    pub fn mention(&self) -> String {
+        format!("<@{}>", self.id)
+    }
+
+    /// Returns the URL of the avatar of the user, it's a gif if the avatar is animated. If the
+    /// user doesn't have an avatar, it's the URL of the default avatar. The `size` must be a
+    /// power of 2 between 16 and 4096.
+    pub fn avatar_url(&self, size: u16) -> String {
+        match &self.avatar {
+            Some(avatar) => {
+                let extension = if avatar.starts_with("a_") { "gif" } else { "png" };
+                format!("{}/avatars/{}/{}.{}?size={}", CDN_URL, self.id, avatar, extension, size)
+            }
+            None => {
+                let discriminator = self.discriminator.parse::<u16>().unwrap_or(0);
+                format!("{}/embed/avatars/{}.png?size={}", CDN_URL, discriminator % 5, size)
+            }
+        }
+    }
+}
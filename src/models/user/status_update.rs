@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+/// The presence of the bot, it can be sent in the IDENTIFY with [`ConfigBuilder.set_presence`]
+/// or updated at runtime with [`SessionData.update_status`]
+///
+/// [`ConfigBuilder.set_presence`]: ../../client/config/struct.ConfigBuilder.html#method.set_presence
+/// [`SessionData.update_status`]: ../../client/struct.SessionData.html#method.update_status
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct StatusUpdate {
     since: Option<u64>,
     game: Option<Activity>,
@@ -12,6 +17,8 @@ pub struct StatusUpdate {
 }
 
 /// The user new status
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum Status {
     /// Online
     Online,
@@ -20,6 +27,7 @@ pub enum Status {
     Dnd,
 
     /// AFK
+    #[serde(rename = "idle")]
     AFK,
 
     /// Invisible and shown as offline
@@ -46,7 +54,7 @@ impl StatusUpdate {
         StatusUpdate {
             since: None,
             game: None,
-            status: String::new(),
+            status: format!("{}", Status::Online),
             afk: false,
         }
     }
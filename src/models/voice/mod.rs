@@ -3,10 +3,12 @@
 use crate::models::guild::GuildMember;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+/// The voice connection state of a user
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct VoiceState {
     pub guild_id: Option<String>,
-    pub channel_id: String,
+    /// The voice channel the user is connected to, it's None when the user disconnects
+    pub channel_id: Option<String>,
     pub user_id: String,
     pub member: Option<GuildMember>,
     pub session_id: String,
@@ -15,5 +17,5 @@ pub struct VoiceState {
     pub self_deaf: bool,
     pub self_mute: bool,
     pub self_stream: Option<bool>,
-    pub supress: bool,
+    pub suppress: bool,
 }
@@ -0,0 +1,29 @@
+//! Webhook related models
+
+use crate::models::{snowflake::Snowflake, user::User};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Webhook {
+    pub id: Snowflake,
+    #[serde(rename = "type")]
+    pub kind: WebhookKind,
+    pub guild_id: Option<String>,
+    pub channel_id: String,
+    /// The user that created the webhook, it's not sent when the webhook is get with its token
+    pub user: Option<User>,
+    pub name: Option<String>,
+    pub avatar: Option<String>,
+    /// The secure token of the webhook, it's only sent for incoming webhooks
+    pub token: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize_repr, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum WebhookKind {
+    /// Incoming webhooks can post messages to channels with a token
+    Incoming = 1,
+    /// Internal webhooks used with channel following to post new messages into channels
+    ChannelFollower = 2,
+}
@@ -19,6 +19,21 @@ where
     return JoinHandle::AsyncStd(async_std::task::spawn(future));
 }
 
+/// Runs a future until it completes in the current thread, it must not be used inside async code
+#[cfg(any(feature = "blocking", test))]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    #[cfg(feature = "tokio-runtime")]
+    return tokio::runtime::Builder::new()
+        .basic_scheduler()
+        .enable_time()
+        .build()
+        .expect("Can't create the blocking runtime")
+        .block_on(future);
+
+    #[cfg(feature = "async-std-runtime")]
+    return async_std::task::block_on(future);
+}
+
 pub(crate) fn sleep(duration: Duration) -> delay::Delay {
     #[cfg(feature = "tokio-runtime")]
     return Delay::Tokio(tokio::time::delay_for(duration));
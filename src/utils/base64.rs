@@ -0,0 +1,60 @@
+//! Minimal base64 encoder, used to send images in JSON bodies as data URIs
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes the data with the standard base64 alphabet and padding
+pub(crate) fn encode(data: &[u8]) -> String {
+    let mut output = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        // Each chunk of 3 bytes is 4 characters, the missing bytes are padded with '='
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+
+    output
+}
+
+/// Returns the data URI of an image, the type is detected from the first bytes of the image,
+/// only PNG, JPEG and GIF images are supported by Discord
+pub(crate) fn image_data_uri(image: &[u8]) -> String {
+    let mime = if image.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if image.starts_with(b"GIF8") {
+        "image/gif"
+    } else {
+        "image/jpeg"
+    };
+
+    format!("data:{};base64,{}", mime, encode(image))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+
+        for (data, expected) in &vectors {
+            assert_eq!(encode(data.as_bytes()), *expected);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::error::{PandaError, Result};
+
+use serde::Serialize;
+
+/// Options used to ban a member with [`HttpClient.ban_member`]
+///
+/// [`HttpClient.ban_member`]: ../../../struct.HttpClient.html#method.ban_member
+#[derive(Debug, Default, Serialize)]
+pub struct BanOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_message_days: Option<u8>,
+    // Sent in the X-Audit-Log-Reason header
+    #[serde(skip)]
+    pub(crate) reason: Option<String>,
+}
+
+impl BanOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of days (0-7) of messages sent by the banned user to delete
+    pub fn delete_message_days(mut self, days: u8) -> Self {
+        self.delete_message_days = Some(days);
+
+        self
+    }
+
+    /// Set the reason of the ban, it will be shown in the audit log
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+
+        self
+    }
+
+    pub(crate) fn check(&self) -> Result<()> {
+        match self.delete_message_days {
+            Some(days) if days > 7 => Err(PandaError::InvalidInput(format!(
+                "delete_message_days must be between 0 and 7, got {}",
+                days
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
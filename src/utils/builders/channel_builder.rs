@@ -0,0 +1,104 @@
+use crate::models::channel::{ChannelKind, Overwrite};
+use serde::Serialize;
+
+/// Builder used to create a guild channel with [`HttpClient.create_channel`]
+///
+/// [`HttpClient.create_channel`]: ../../../struct.HttpClient.html#method.create_channel
+#[derive(Debug, Serialize)]
+pub struct ChannelBuilder {
+    name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<ChannelKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bitrate: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit_per_user: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    permission_overwrites: Vec<Overwrite>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsfw: Option<bool>,
+}
+
+impl ChannelBuilder {
+    /// Creates a new builder with the name of the channel (2-100 characters)
+    pub fn new(name: impl Into<String>) -> Self {
+        ChannelBuilder {
+            name: name.into(),
+            kind: None,
+            topic: None,
+            bitrate: None,
+            user_limit: None,
+            rate_limit_per_user: None,
+            position: None,
+            permission_overwrites: Vec::new(),
+            parent_id: None,
+            nsfw: None,
+        }
+    }
+
+    /// Set the type of the channel, default is a text channel
+    pub fn kind(mut self, kind: ChannelKind) -> Self {
+        self.kind = Some(kind);
+
+        self
+    }
+
+    pub fn topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = Some(topic.into());
+
+        self
+    }
+
+    /// Set the bitrate (in bits) of the voice channel
+    pub fn bitrate(mut self, bitrate: u64) -> Self {
+        self.bitrate = Some(bitrate);
+
+        self
+    }
+
+    /// Set the user limit of the voice channel
+    pub fn user_limit(mut self, user_limit: u64) -> Self {
+        self.user_limit = Some(user_limit);
+
+        self
+    }
+
+    pub fn rate_limit_per_user(mut self, rate_limit_per_user: u64) -> Self {
+        self.rate_limit_per_user = Some(rate_limit_per_user);
+
+        self
+    }
+
+    pub fn position(mut self, position: u64) -> Self {
+        self.position = Some(position);
+
+        self
+    }
+
+    pub fn permission_overwrite(mut self, permission_overwrite: Overwrite) -> Self {
+        self.permission_overwrites.push(permission_overwrite);
+
+        self
+    }
+
+    /// Set the category of the channel
+    pub fn parent_id(mut self, parent_id: impl Into<String>) -> Self {
+        self.parent_id = Some(parent_id.into());
+
+        self
+    }
+
+    pub fn is_nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+
+        self
+    }
+}
@@ -1,17 +1,30 @@
 use crate::models::channel::Overwrite;
 use serde::{Deserialize, Serialize};
 
+/// Builder used to edit a channel with [`HttpClient.edit_channel`], only the fields
+/// that were set are sent to Discord.
+///
+/// [`HttpClient.edit_channel`]: ../../../struct.HttpClient.html#method.edit_channel
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct ChannelEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     position: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     topic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     nsfw: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     rate_limit_per_user: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     bitrate: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     user_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     permission_overwrites: Option<Vec<Overwrite>>,
-    parent_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<String>,
 }
 
 impl ChannelEdit {
@@ -71,8 +84,8 @@ impl ChannelEdit {
         self
     }
 
-    pub fn parent_id(mut self, parent_id: u64) -> Self {
-        self.parent_id = Some(parent_id);
+    pub fn parent_id(mut self, parent_id: impl Into<String>) -> Self {
+        self.parent_id = Some(parent_id.into());
 
         self
     }
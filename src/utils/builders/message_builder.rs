@@ -0,0 +1,149 @@
+use crate::models::channel::Embed;
+use serde::{Deserialize, Serialize};
+
+/// Builder used to create a message with [`HttpClient.send_message_with`], only the fields
+/// that were set are sent to Discord.
+///
+/// [`HttpClient.send_message_with`]: ../../../struct.HttpClient.html#method.send_message_with
+#[derive(Debug, Default, Serialize)]
+pub struct MessageBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embed: Option<Embed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_reference: Option<ReplyReference>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sticker_ids: Vec<String>,
+}
+
+/// The `message_reference` sent to reply a message
+#[derive(Debug, Serialize)]
+struct ReplyReference {
+    message_id: String,
+    fail_if_not_exists: bool,
+}
+
+/// Controls which mentions of a message will ping users and roles, nothing is pinged unless
+/// it's allowed. Without it Discord pings all the mentions in the content, even @everyone.
+/// [Discord Documentation](https://discord.com/developers/docs/resources/channel#allowed-mentions-object)
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AllowedMentions {
+    #[serde(default)]
+    parse: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    users: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    roles: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replied_user: Option<bool>,
+}
+
+impl MessageBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the message contents (up to 2000 characters)
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+
+        self
+    }
+
+    /// Set the embed of the message
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embed = Some(embed);
+
+        self
+    }
+
+    /// Set if it's a TTS message
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = Some(tts);
+
+        self
+    }
+
+    /// Set the allowed mentions of the message
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+
+        self
+    }
+
+    /// Make the message a reply to the given message of the same channel. If
+    /// `fail_if_not_exists` is false and the message was deleted, it's sent as a normal message
+    pub fn reply_to(mut self, message_id: impl Into<String>, fail_if_not_exists: bool) -> Self {
+        self.message_reference = Some(ReplyReference {
+            message_id: message_id.into(),
+            fail_if_not_exists,
+        });
+
+        self
+    }
+
+    /// Add a sticker to the message, up to 3 stickers can be sent
+    pub fn sticker(mut self, sticker_id: impl Into<String>) -> Self {
+        self.sticker_ids.push(sticker_id.into());
+
+        self
+    }
+}
+
+impl AllowedMentions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Suppress all the pings, it's the same as `AllowedMentions::new()`
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Allow pings for all users mentioned in the content
+    pub fn parse_users(mut self) -> Self {
+        self.parse.push("users".into());
+
+        self
+    }
+
+    /// Allow pings for all roles mentioned in the content
+    pub fn parse_roles(mut self) -> Self {
+        self.parse.push("roles".into());
+
+        self
+    }
+
+    /// Allow @everyone and @here pings
+    pub fn parse_everyone(mut self) -> Self {
+        self.parse.push("everyone".into());
+
+        self
+    }
+
+    /// Allow pings for the given user ID
+    pub fn user(mut self, user_id: impl Into<String>) -> Self {
+        self.users.push(user_id.into());
+
+        self
+    }
+
+    /// Allow pings for the given role ID
+    pub fn role(mut self, role_id: impl Into<String>) -> Self {
+        self.roles.push(role_id.into());
+
+        self
+    }
+
+    /// Set if the author of the replied message is pinged, false by default
+    pub fn replied_user(mut self, replied_user: bool) -> Self {
+        self.replied_user = Some(replied_user);
+
+        self
+    }
+}
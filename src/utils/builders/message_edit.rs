@@ -1,9 +1,21 @@
+use super::AllowedMentions;
+use crate::models::channel::Embed;
 use serde::{Deserialize, Serialize};
+
+/// Builder used to edit a message with [`HttpClient.edit_message`], only the fields
+/// that were set are sent to Discord.
+///
+/// [`HttpClient.edit_message`]: ../../../struct.HttpClient.html#method.edit_message
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct MessageEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
-    embed: Option<()>,
-    flags: u64, // TODO: SEE FLAGS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embed: Option<Embed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
 }
 
 impl MessageEdit {
@@ -17,11 +29,23 @@ impl MessageEdit {
         self
     }
 
-    pub fn embed(self) {
-        unimplemented!()
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embed = Some(embed);
+
+        self
     }
 
-    pub fn flags(self) {
-        unimplemented!()
+    /// Set the message flags, only SUPPRESS_EMBEDS (1 << 2) can be set
+    pub fn flags(mut self, flags: u64) -> Self {
+        self.flags = Some(flags);
+
+        self
+    }
+
+    /// Set the allowed mentions of the new content
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+
+        self
     }
 }
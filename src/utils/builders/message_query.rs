@@ -0,0 +1,88 @@
+use crate::{
+    error::{PandaError, Result},
+    utils::percent_encode_component,
+};
+
+/// Query used to get the messages of a channel with [`HttpClient.get_channel_messages`], only
+/// one of `before`, `after` and `around` can be set
+///
+/// [`HttpClient.get_channel_messages`]: ../../../struct.HttpClient.html#method.get_channel_messages
+#[derive(Clone, Debug, Default)]
+pub struct MessageQuery {
+    before: Option<String>,
+    after: Option<String>,
+    around: Option<String>,
+    limit: Option<u8>,
+}
+
+impl MessageQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the messages sent before the given message
+    pub fn before(mut self, message_id: impl Into<String>) -> Self {
+        self.before = Some(message_id.into());
+
+        self
+    }
+
+    /// Get the messages sent after the given message
+    pub fn after(mut self, message_id: impl Into<String>) -> Self {
+        self.after = Some(message_id.into());
+
+        self
+    }
+
+    /// Get the messages sent around the given message
+    pub fn around(mut self, message_id: impl Into<String>) -> Self {
+        self.around = Some(message_id.into());
+
+        self
+    }
+
+    /// Set the max number of messages to return (1-100), 50 by default
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    pub(crate) fn check(&self) -> Result<()> {
+        let anchors = [&self.before, &self.after, &self.around];
+
+        if anchors.iter().filter(|anchor| anchor.is_some()).count() > 1 {
+            return Err(PandaError::InvalidInput(
+                "only one of before, after and around can be set".into(),
+            ));
+        }
+
+        match self.limit {
+            Some(limit) if limit == 0 || limit > 100 => Err(PandaError::InvalidInput(format!(
+                "the messages limit must be between 1 and 100, but it was {}",
+                limit
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the query string, without the leading `?`
+    pub(crate) fn to_query(&self) -> String {
+        let mut query = Vec::new();
+
+        if let Some(before) = &self.before {
+            query.push(format!("before={}", percent_encode_component(before)));
+        }
+        if let Some(after) = &self.after {
+            query.push(format!("after={}", percent_encode_component(after)));
+        }
+        if let Some(around) = &self.around {
+            query.push(format!("around={}", percent_encode_component(around)));
+        }
+        if let Some(limit) = self.limit {
+            query.push(format!("limit={}", limit));
+        }
+
+        query.join("&")
+    }
+}
@@ -1,5 +1,19 @@
+mod ban_options;
+mod channel_builder;
 mod channel_edit;
+mod member_edit;
+mod message_builder;
 mod message_edit;
+mod message_query;
+mod role_builder;
+mod webhook_execute_builder;
 
+pub use ban_options::BanOptions;
+pub use channel_builder::ChannelBuilder;
 pub use channel_edit::ChannelEdit;
+pub use member_edit::MemberEdit;
+pub use message_builder::{AllowedMentions, MessageBuilder};
 pub use message_edit::MessageEdit;
+pub use message_query::MessageQuery;
+pub use role_builder::RoleBuilder;
+pub use webhook_execute_builder::WebhookExecuteBuilder;
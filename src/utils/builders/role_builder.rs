@@ -0,0 +1,60 @@
+use crate::models::guild::Permissions;
+
+use serde::Serialize;
+
+/// Builder used to create or edit a role with [`HttpClient.create_role`] or [`HttpClient.edit_role`]
+///
+/// [`HttpClient.create_role`]: ../../../struct.HttpClient.html#method.create_role
+/// [`HttpClient.edit_role`]: ../../../struct.HttpClient.html#method.edit_role
+#[derive(Debug, Default, Serialize)]
+pub struct RoleBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<Permissions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hoist: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mentionable: Option<bool>,
+}
+
+impl RoleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+
+        self
+    }
+
+    /// Set the permissions of the role
+    pub fn permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = Some(permissions);
+
+        self
+    }
+
+    /// Set the color of the role, as an integer RGB value
+    pub fn color(mut self, color: u64) -> Self {
+        self.color = Some(color);
+
+        self
+    }
+
+    /// Set if the role should be displayed separately in the sidebar
+    pub fn hoist(mut self, hoist: bool) -> Self {
+        self.hoist = Some(hoist);
+
+        self
+    }
+
+    pub fn mentionable(mut self, mentionable: bool) -> Self {
+        self.mentionable = Some(mentionable);
+
+        self
+    }
+}
@@ -0,0 +1,71 @@
+use super::AllowedMentions;
+use crate::models::channel::Embed;
+use serde::Serialize;
+
+/// Builder used to send a message with a webhook with [`HttpClient.execute_webhook`], only the
+/// fields that were set are sent to Discord. The message needs a content or an embed.
+///
+/// [`HttpClient.execute_webhook`]: ../../../struct.HttpClient.html#method.execute_webhook
+#[derive(Debug, Default, Serialize)]
+pub struct WebhookExecuteBuilder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tts: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    embeds: Vec<Embed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
+}
+
+impl WebhookExecuteBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the message contents (up to 2000 characters)
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+
+        self
+    }
+
+    /// Override the default username of the webhook for this message
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+
+        self
+    }
+
+    /// Override the default avatar of the webhook for this message
+    pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
+        self.avatar_url = Some(avatar_url.into());
+
+        self
+    }
+
+    /// Set if it's a TTS message
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = Some(tts);
+
+        self
+    }
+
+    /// Add an embed to the message, webhooks can send up to 10 embeds
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+
+        self
+    }
+
+    /// Set the allowed mentions of the message
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+
+        self
+    }
+}
@@ -0,0 +1,57 @@
+//! Random factors used to spread the reconnections and heartbeats of the shards
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Incremented on every call, so two calls in the same nanosecond don't return the same factor
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Returns a random factor in [0, 1). It mixes the current time with the shard id, so shards
+/// that reconnect at the same time don't get the same factor.
+pub(crate) fn jitter(shard_id: u64) -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    let seed = nanos ^ shard_id.wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ count.rotate_left(32);
+
+    // The top 53 bits fit exactly in the mantissa of a f64
+    (splitmix64(seed) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// The SplitMix64 finalizer, it spreads every bit of the seed over the whole output
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jitter_is_in_range() {
+        for shard_id in 0..100 {
+            let factor = jitter(shard_id);
+            assert!((0.0..1.0).contains(&factor), "{}", factor);
+        }
+    }
+
+    #[test]
+    fn shards_get_different_factors() {
+        let factors: Vec<f64> = (0..16).map(jitter).collect();
+
+        for (i, a) in factors.iter().enumerate() {
+            for b in &factors[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+}
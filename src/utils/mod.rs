@@ -1 +1,6 @@
+pub(crate) mod base64;
 pub mod builders;
+pub(crate) mod jitter;
+mod percent_encode;
+
+pub use percent_encode::percent_encode_component;
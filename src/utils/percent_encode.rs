@@ -0,0 +1,51 @@
+/// Percent-encodes a string to be used as a component of an URL, like a path segment or a query
+/// value. Only the unreserved characters of RFC 3986 are kept, everything else is encoded byte
+/// by byte, including the `:` of the custom emojis.
+///
+/// ```
+/// use panda::utils::percent_encode_component;
+///
+/// assert_eq!(percent_encode_component("👍"), "%F0%9F%91%8D");
+/// assert_eq!(percent_encode_component("panda:123456"), "panda%3A123456");
+/// assert_eq!(percent_encode_component("a b/c?d&e=f"), "a%20b%2Fc%3Fd%26e%3Df");
+/// assert_eq!(percent_encode_component("AZaz09-_.~"), "AZaz09-_.~");
+/// ```
+pub fn percent_encode_component(data: impl AsRef<str>) -> String {
+    let mut escaped = String::new();
+    for b in data.as_ref().as_bytes().iter() {
+        match *b as char {
+            // Accepted characters
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => escaped.push(*b as char),
+
+            // Everything else is percent-encoded
+            _ => escaped.push_str(&format!("%{:02X}", b)),
+        };
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_emojis() {
+        assert_eq!(percent_encode_component("👍"), "%F0%9F%91%8D");
+        assert_eq!(percent_encode_component("❤️"), "%E2%9D%A4%EF%B8%8F");
+        assert_eq!(percent_encode_component("🇫🇷"), "%F0%9F%87%AB%F0%9F%87%B7");
+    }
+
+    #[test]
+    fn custom_emojis() {
+        assert_eq!(percent_encode_component("panda:123456"), "panda%3A123456");
+        assert_eq!(percent_encode_component("a_b-c:1"), "a_b-c%3A1");
+    }
+
+    #[test]
+    fn unreserved_characters_are_kept() {
+        assert_eq!(percent_encode_component("AZaz09-_.~"), "AZaz09-_.~");
+        assert_eq!(percent_encode_component("a b/c?d&e=f%"), "a%20b%2Fc%3Fd%26e%3Df%25");
+        assert_eq!(percent_encode_component(""), "");
+    }
+}